@@ -0,0 +1,184 @@
+// SPDX-License-Identifier: Apache-2.0
+//! Synthetic workload generator and benchmark harness for the event
+//! ingestion pipeline: feeds generated JSON lines through the exact
+//! parse -> `EventData` -> `Dashboard::add_event` path used in production
+//! (headless, no terminal), so throughput/latency regressions show up as new
+//! tabs/counters are added.
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::{Duration, Instant};
+use sysinfo::{System, SystemExt};
+use tokio::sync::mpsc;
+
+use crate::app_cli_dashboard::{Dashboard, EventData};
+
+/// One phase of sustained synthetic load.
+#[derive(Debug, Clone, Deserialize)]
+pub struct WorkloadPhase {
+    /// Target events/sec during this phase.
+    pub event_rate: f64,
+    pub duration_ms: u64,
+    /// Weighted distribution over event types, e.g. `{"exec": 0.5, "open": 0.5}`.
+    pub etype_mix: BTreeMap<String, f64>,
+    #[serde(default = "default_cardinality")]
+    pub distinct_pids: u32,
+    #[serde(default = "default_cardinality")]
+    pub distinct_comms: u32,
+}
+
+fn default_cardinality() -> u32 {
+    16
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Workload {
+    pub phases: Vec<WorkloadPhase>,
+}
+
+impl Workload {
+    pub async fn from_file(path: &Path) -> Result<Self> {
+        let content = tokio::fs::read_to_string(path).await.context("read workload file")?;
+        serde_json::from_str(&content).context("parse workload JSON")
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct BenchReport {
+    pub events_sent: u64,
+    pub events_ingested: u64,
+    /// Events the generator couldn't push because the 1024-slot channel was full.
+    pub dropped: u64,
+    pub duration_ms: u64,
+    pub throughput_eps: f64,
+    pub p50_latency_us: f64,
+    pub p99_latency_us: f64,
+    pub peak_memory_bytes: u64,
+}
+
+/// Deterministic weighted pick over `etype_mix`, seeded by a running counter
+/// so bench runs are reproducible across commits instead of depending on a
+/// `rand` dependency this crate doesn't otherwise need.
+fn pick_etype(mix: &BTreeMap<String, f64>, counter: u64) -> &str {
+    let total: f64 = mix.values().sum();
+    let target = if total > 0.0 {
+        (counter as f64 * 0.618_033_988_75).fract() * total
+    } else {
+        0.0
+    };
+    let mut acc = 0.0;
+    for (etype, weight) in mix {
+        acc += weight;
+        if target <= acc {
+            return etype;
+        }
+    }
+    mix.keys().next().map(|s| s.as_str()).unwrap_or("unknown")
+}
+
+fn synthesize_line(phase: &WorkloadPhase, counter: u64) -> String {
+    let etype = pick_etype(&phase.etype_mix, counter);
+    let pid = 1000 + counter % phase.distinct_pids.max(1) as u64;
+    let comm_idx = counter % phase.distinct_comms.max(1) as u64;
+    serde_json::json!({
+        "etype": etype,
+        "pid": pid,
+        "comm": format!("bench-{comm_idx}"),
+        "file": format!("/bench/file-{counter}"),
+        "uid": 1000,
+    })
+    .to_string()
+}
+
+/// Peak resident set size of this process, in bytes, from `/proc/self/status`.
+fn peak_memory_bytes() -> u64 {
+    let Ok(status) = std::fs::read_to_string("/proc/self/status") else {
+        return 0;
+    };
+    status
+        .lines()
+        .find(|line| line.starts_with("VmHWM:"))
+        .and_then(|line| line.split_whitespace().nth(1))
+        .and_then(|kb| kb.parse::<u64>().ok())
+        .map(|kb| kb * 1024)
+        .unwrap_or(0)
+}
+
+fn percentile(sorted_us: &[f64], pct: f64) -> f64 {
+    if sorted_us.is_empty() {
+        return 0.0;
+    }
+    let idx = ((sorted_us.len() - 1) as f64 * pct).round() as usize;
+    sorted_us[idx]
+}
+
+/// Run every phase of `workload` headlessly through the real ingestion path
+/// and report throughput/latency/drop stats.
+pub async fn run(workload: Workload) -> Result<BenchReport> {
+    let (tx, mut rx) = mpsc::channel::<(Instant, String)>(1024);
+
+    let phases = workload.phases.clone();
+    let generator = tokio::spawn(async move {
+        let mut sent = 0u64;
+        let mut dropped = 0u64;
+        let mut counter = 0u64;
+
+        for phase in &phases {
+            let interval = Duration::from_secs_f64(1.0 / phase.event_rate.max(1.0));
+            let phase_end = Instant::now() + Duration::from_millis(phase.duration_ms);
+            while Instant::now() < phase_end {
+                let line = synthesize_line(phase, counter);
+                counter += 1;
+                match tx.try_send((Instant::now(), line)) {
+                    Ok(()) => sent += 1,
+                    Err(_) => dropped += 1,
+                }
+                tokio::time::sleep(interval).await;
+            }
+        }
+
+        (sent, dropped)
+    });
+
+    let mut dashboard = Dashboard::new(250, 1000, 60);
+    let mut system = System::new_all();
+    let mut latencies_us = Vec::new();
+    let mut ingested = 0u64;
+    let mut last_stats_refresh = Instant::now();
+
+    while let Some((sent_at, line)) = rx.recv().await {
+        latencies_us.push(sent_at.elapsed().as_micros() as f64);
+        if let Some(event) = EventData::from_json_line(&line) {
+            dashboard.add_event(event);
+            ingested += 1;
+        }
+        // Mirror the dashboard's own update cadence instead of refreshing
+        // system stats on every event, so the bench doesn't pay a syscall per event.
+        if last_stats_refresh.elapsed() >= dashboard.update_rate {
+            dashboard.update_system_stats(&mut system);
+            last_stats_refresh = Instant::now();
+        }
+    }
+
+    let (sent, dropped) = generator.await.context("generator task panicked")?;
+
+    latencies_us.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let total_duration_ms: u64 = workload.phases.iter().map(|p| p.duration_ms).sum();
+
+    Ok(BenchReport {
+        events_sent: sent,
+        events_ingested: ingested,
+        dropped,
+        duration_ms: total_duration_ms,
+        throughput_eps: if total_duration_ms > 0 {
+            ingested as f64 / (total_duration_ms as f64 / 1000.0)
+        } else {
+            0.0
+        },
+        p50_latency_us: percentile(&latencies_us, 0.50),
+        p99_latency_us: percentile(&latencies_us, 0.99),
+        peak_memory_bytes: peak_memory_bytes(),
+    })
+}
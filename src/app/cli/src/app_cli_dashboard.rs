@@ -1,19 +1,23 @@
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
+    symbols,
     text::{Line, Text},
     widgets::{
-        Block, Borders, Clear, Gauge, List, ListItem, Paragraph, Row, Sparkline, Table, Tabs,
-        Wrap,
+        Axis, Block, Borders, Chart, Clear, Dataset, Gauge, GraphType, List, ListItem, ListState,
+        Paragraph, Row, Table, Tabs, Wrap,
     },
     Frame,
 };
 use std::{
-    collections::{BTreeMap, VecDeque},
-    time::{SystemTime, UNIX_EPOCH},
+    collections::{BTreeMap, HashSet, VecDeque},
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 use sysinfo::{System, SystemExt, CpuExt};
 
+use crate::app_cli_policy::PolicyStatus;
+use crate::app_cli_sockets::SocketTable;
+
 #[derive(Debug, Clone)]
 pub struct EventData {
     pub timestamp: u64,
@@ -22,6 +26,144 @@ pub struct EventData {
     pub comm: String,
     pub file: String,
     pub uid: u32,
+    /// Populated for `network`/`connect`/`accept` events; `None` for everything else.
+    pub local_addr: Option<String>,
+    pub remote_addr: Option<String>,
+    pub proto: Option<String>,
+    pub state: Option<String>,
+}
+
+impl EventData {
+    /// Parse one line of the agent's JSON event stream. Shared by the live
+    /// dashboard, the export sink, and record/replay so they stay in sync.
+    pub fn from_json_line(line: &str) -> Option<EventData> {
+        let event: serde_json::Value = serde_json::from_str(line).ok()?;
+        Some(EventData {
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
+            event_type: event.get("etype").and_then(|v| v.as_str()).unwrap_or("unknown").to_string(),
+            pid: event.get("pid").and_then(|v| v.as_u64()).unwrap_or(0) as u32,
+            comm: event.get("comm").and_then(|v| v.as_str()).unwrap_or("unknown").to_string(),
+            file: event.get("file").and_then(|v| v.as_str()).unwrap_or("").to_string(),
+            uid: event.get("uid").and_then(|v| v.as_u64()).unwrap_or(0) as u32,
+            local_addr: event.get("local_addr").and_then(|v| v.as_str()).map(|s| s.to_string()),
+            remote_addr: event.get("remote_addr").and_then(|v| v.as_str()).map(|s| s.to_string()),
+            proto: event.get("proto").and_then(|v| v.as_str()).map(|s| s.to_string()),
+            state: event.get("state").and_then(|v| v.as_str()).map(|s| s.to_string()),
+        })
+    }
+}
+
+/// One destination a process has been observed talking to, aggregated for
+/// the Connections tab.
+#[derive(Debug, Clone)]
+pub struct ConnectionStat {
+    pub remote_addr: String,
+    pub proto: String,
+    pub count: u64,
+    pub last_seen: u64,
+    /// First time this destination was seen from any process this session —
+    /// surfaced so an operator can spot exfiltration/C2 beaconing to a host
+    /// nothing has talked to before.
+    pub is_new_destination: bool,
+}
+
+/// Which scrollable panel keyboard navigation currently applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Panel {
+    Events,
+    Processes,
+}
+
+/// Column the process table/list is ordered by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    Count,
+    Name,
+}
+
+impl SortKey {
+    fn label(&self) -> &'static str {
+        match self {
+            SortKey::Count => "Count",
+            SortKey::Name => "Name",
+        }
+    }
+
+    fn next(&self) -> SortKey {
+        match self {
+            SortKey::Count => SortKey::Name,
+            SortKey::Name => SortKey::Count,
+        }
+    }
+}
+
+/// Sort state for the process column; bottom's `c`/sort-key convention: a
+/// column sorts descending by default, pressing the direction key again
+/// reverses it.
+#[derive(Debug, Clone, Copy)]
+pub struct SortState {
+    pub key: SortKey,
+    pub ascending: bool,
+}
+
+impl Default for SortState {
+    fn default() -> Self {
+        Self {
+            key: SortKey::Count,
+            ascending: false,
+        }
+    }
+}
+
+/// Live search/filter state for the EVENTS tab.
+///
+/// Tracks a blank query and an invalid regex as distinct states so the UI can
+/// show "type to search" and "bad pattern" differently instead of both just
+/// matching nothing.
+pub struct SearchState {
+    pub active: bool,
+    pub query: String,
+    /// Byte offset into `query` (not a char count), so it can be used directly
+    /// with `String::insert`/`String::remove` without landing mid-character.
+    pub cursor: usize,
+    pub regex: Option<regex::Regex>,
+    pub is_blank_search: bool,
+    pub is_invalid_search: bool,
+}
+
+impl Default for SearchState {
+    fn default() -> Self {
+        Self {
+            active: false,
+            query: String::new(),
+            cursor: 0,
+            regex: None,
+            is_blank_search: true,
+            is_invalid_search: false,
+        }
+    }
+}
+
+impl SearchState {
+    fn recompile(&mut self) {
+        if self.query.is_empty() {
+            self.is_blank_search = true;
+            self.is_invalid_search = false;
+            self.regex = None;
+            return;
+        }
+        self.is_blank_search = false;
+        match regex::Regex::new(&self.query) {
+            Ok(re) => {
+                self.regex = Some(re);
+                self.is_invalid_search = false;
+            }
+            Err(_) => {
+                self.regex = None;
+                self.is_invalid_search = true;
+            }
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -37,6 +179,9 @@ pub struct Dashboard {
     pub events: VecDeque<EventData>,
     pub event_counters: BTreeMap<String, u64>,
     pub process_counters: BTreeMap<String, u64>,
+    /// Most recently seen PID for each `comm`, so an operator can act on a process
+    /// they only know by name from the aggregated counters.
+    pub process_pids: BTreeMap<String, u32>,
     pub anomaly_scores: VecDeque<f64>,
     pub system_stats: SystemStats,
     pub total_events: u64,
@@ -44,14 +189,47 @@ pub struct Dashboard {
     pub current_tab: usize,
     pub show_help: bool,
     pub paused: bool,
+    pub events_state: ListState,
+    pub processes_state: ListState,
+    pub focused_panel: Panel,
+    pub search: SearchState,
+    /// How often `render` is expected to be called (UI responsiveness).
+    pub tick_rate: Duration,
+    /// How often `update` (system stats + anomaly scoring) should run.
+    pub update_rate: Duration,
+    /// Width, in seconds, of the sliding window used for event/process counters
+    /// and rate scoring. Events older than this are evicted on every update tick.
+    pub window_secs: u64,
+    /// Process awaiting a second `d` keystroke to confirm termination.
+    pub pending_kill: Option<(String, u32)>,
+    /// Outcome of the last kill attempt, shown in the status bar.
+    pub last_kill_status: Option<String>,
+    /// Active sort column/direction for the process table/list.
+    pub process_sort: SortState,
+    /// Active policy and live per-rule match counts, refreshed from the daemon
+    /// on the slow `update` cadence. `None` when no daemon is reachable (e.g.
+    /// during replay).
+    pub policy_status: Option<PolicyStatus>,
+    /// Active destinations per process, for the Connections tab.
+    pub connections: BTreeMap<String, Vec<ConnectionStat>>,
+    /// Every remote address seen this session, used to flag first-contact destinations.
+    known_destinations: HashSet<String>,
+    /// Resolves (local_addr, remote_addr, proto) to an owning pid/comm for
+    /// network events the agent couldn't attribute itself; refresh-gated
+    /// internally so lookups don't stall the render loop.
+    socket_table: SocketTable,
 }
 
 impl Dashboard {
-    pub fn new() -> Self {
+    /// `tick_rate_ms` paces redraws/input; `update_rate_ms` paces the slower
+    /// system-stats/anomaly-scoring pass, mirroring bottom's tick-vs-update split.
+    /// `window_secs` bounds how far back counters/rates look (default 60s).
+    pub fn new(tick_rate_ms: u64, update_rate_ms: u64, window_secs: u64) -> Self {
         Self {
             events: VecDeque::with_capacity(1000),
             event_counters: BTreeMap::new(),
             process_counters: BTreeMap::new(),
+            process_pids: BTreeMap::new(),
             anomaly_scores: VecDeque::with_capacity(60),
             system_stats: SystemStats {
                 cpu_usage: 0.0,
@@ -65,14 +243,242 @@ impl Dashboard {
             current_tab: 0,
             show_help: false,
             paused: false,
+            events_state: ListState::default(),
+            processes_state: ListState::default(),
+            focused_panel: Panel::Events,
+            search: SearchState::default(),
+            tick_rate: Duration::from_millis(tick_rate_ms),
+            update_rate: Duration::from_millis(update_rate_ms),
+            window_secs,
+            pending_kill: None,
+            last_kill_status: None,
+            process_sort: SortState::default(),
+            policy_status: None,
+            connections: BTreeMap::new(),
+            known_destinations: HashSet::new(),
+            socket_table: SocketTable::new(Duration::from_secs(5)),
         }
     }
 
-    pub fn add_event(&mut self, event: EventData) {
+    pub fn cycle_sort_key(&mut self) {
+        self.process_sort.key = self.process_sort.key.next();
+        self.process_sort.ascending = false;
+    }
+
+    pub fn toggle_sort_direction(&mut self) {
+        self.process_sort.ascending = !self.process_sort.ascending;
+    }
+
+    /// Processes ordered per `process_sort`, with an arrow suffix describing it
+    /// for use in the panel title (ratatui `List`s have no per-column headers).
+    fn sorted_processes(&self) -> (Vec<(&String, &u64)>, String) {
+        let mut processes: Vec<_> = self.process_counters.iter().collect();
+        match self.process_sort.key {
+            SortKey::Count => processes.sort_by_key(|(_, count)| **count),
+            SortKey::Name => processes.sort_by(|a, b| a.0.cmp(b.0)),
+        }
+        if !self.process_sort.ascending {
+            processes.reverse();
+        }
+
+        let arrow = if self.process_sort.ascending { "▲" } else { "▼" };
+        let label = format!("{} {}", self.process_sort.key.label(), arrow);
+        (processes, label)
+    }
+
+    pub fn enter_search(&mut self) {
+        self.search.active = true;
+    }
+
+    pub fn exit_search(&mut self) {
+        self.search.active = false;
+    }
+
+    pub fn search_push_char(&mut self, c: char) {
+        self.search.query.insert(self.search.cursor, c);
+        self.search.cursor += c.len_utf8();
+        self.search.recompile();
+    }
+
+    pub fn search_backspace(&mut self) {
+        if self.search.cursor == 0 {
+            return;
+        }
+        let prev = self.search.query[..self.search.cursor]
+            .char_indices()
+            .next_back()
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+        self.search.query.remove(prev);
+        self.search.cursor = prev;
+        self.search.recompile();
+    }
+
+    pub fn search_cursor_left(&mut self) {
+        self.search.cursor = self.search.query[..self.search.cursor]
+            .char_indices()
+            .next_back()
+            .map(|(i, _)| i)
+            .unwrap_or(0);
+    }
+
+    pub fn search_cursor_right(&mut self) {
+        self.search.cursor = self.search.query[self.search.cursor..]
+            .chars()
+            .next()
+            .map(|c| self.search.cursor + c.len_utf8())
+            .unwrap_or(self.search.query.len());
+    }
+
+    /// `(offset, score)` points for the retained threat-score samples, oldest
+    /// first; `offset` is 0 for the newest sample and drops by 1 per `update` tick.
+    fn anomaly_score_points(&self) -> Vec<(f64, f64)> {
+        let len = self.anomaly_scores.len();
+        self.anomaly_scores
+            .iter()
+            .enumerate()
+            .map(|(i, &score)| ((i as f64) - (len as f64 - 1.0), score))
+            .collect()
+    }
+
+    fn matches_search(&self, event: &EventData) -> bool {
+        if self.search.is_blank_search {
+            return true;
+        }
+        if self.search.is_invalid_search {
+            return false;
+        }
+        if let Some(re) = &self.search.regex {
+            re.is_match(&event.comm) || re.is_match(&event.file) || re.is_match(&event.event_type)
+        } else {
+            let q = self.search.query.to_lowercase();
+            event.comm.to_lowercase().contains(&q)
+                || event.file.to_lowercase().contains(&q)
+                || event.event_type.to_lowercase().contains(&q)
+        }
+    }
+
+    /// Number of events that pass the active search filter, i.e. the row count
+    /// `render_events` actually draws — the bound `scroll_panel`/`clamp_selections`
+    /// must use for the Events panel instead of the unfiltered `self.events.len()`.
+    fn visible_events_len(&self) -> usize {
+        self.events.iter().filter(|event| self.matches_search(event)).count()
+    }
+
+    /// Move the highlighted row in the focused panel by `delta` (negative scrolls up).
+    fn scroll_panel(&mut self, panel: Panel, delta: i32) {
+        let len = match panel {
+            Panel::Events => self.visible_events_len(),
+            Panel::Processes => self.process_counters.len(),
+        };
+        if len == 0 {
+            return;
+        }
+        let state = match panel {
+            Panel::Events => &mut self.events_state,
+            Panel::Processes => &mut self.processes_state,
+        };
+        let current = state.selected().unwrap_or(0) as i32;
+        let next = (current + delta).clamp(0, len as i32 - 1);
+        state.select(Some(next as usize));
+    }
+
+    pub fn on_up(&mut self) {
+        self.scroll_panel(self.focused_panel, -1);
+    }
+
+    pub fn on_down(&mut self) {
+        self.scroll_panel(self.focused_panel, 1);
+    }
+
+    pub fn on_page_up(&mut self) {
+        self.scroll_panel(self.focused_panel, -10);
+    }
+
+    pub fn on_page_down(&mut self) {
+        self.scroll_panel(self.focused_panel, 10);
+    }
+
+    /// The `(comm, pid)` currently highlighted in the Top Processes / Process
+    /// Activity panel, using the same `process_sort` order they render with.
+    fn selected_process(&self) -> Option<(String, u32)> {
+        let index = self.processes_state.selected()?;
+        let (processes, _) = self.sorted_processes();
+        let (comm, _) = processes.get(index)?;
+        let pid = *self.process_pids.get(*comm)?;
+        Some(((*comm).clone(), pid))
+    }
+
+    /// First `d`: arm the confirmation dialog for the selected process.
+    /// Second `d` (via `confirm_kill`) actually sends the signal.
+    pub fn request_kill(&mut self) {
+        if self.focused_panel != Panel::Processes {
+            return;
+        }
+        if let Some(target) = self.selected_process() {
+            self.pending_kill = Some(target);
+        }
+    }
+
+    pub fn cancel_kill(&mut self) {
+        self.pending_kill = None;
+    }
+
+    /// Sends SIGTERM (then relies on the OS/operator for SIGKILL escalation) to
+    /// the process armed by `request_kill`, refusing to touch PID 1 or ourselves.
+    pub fn confirm_kill(&mut self) {
+        let Some((comm, pid)) = self.pending_kill.take() else {
+            return;
+        };
+
+        if pid <= 1 || pid == std::process::id() {
+            self.last_kill_status = Some(format!("refused to kill {} (PID {}): protected", comm, pid));
+            return;
+        }
+
+        use nix::sys::signal::{kill, Signal};
+        use nix::unistd::Pid;
+
+        match kill(Pid::from_raw(pid as i32), Signal::SIGTERM) {
+            Ok(()) => {
+                self.last_kill_status = Some(format!("sent SIGTERM to {} (PID {})", comm, pid));
+            }
+            Err(e) => {
+                self.last_kill_status = Some(format!("failed to kill {} (PID {}): {}", comm, pid, e));
+            }
+        }
+    }
+
+    /// Keep selection indices within bounds after events/processes shrink or grow.
+    fn clamp_selections(&mut self) {
+        let visible_events = self.visible_events_len();
+        if visible_events == 0 {
+            self.events_state.select(None);
+        } else if let Some(sel) = self.events_state.selected() {
+            self.events_state.select(Some(sel.min(visible_events - 1)));
+        }
+
+        if self.process_counters.is_empty() {
+            self.processes_state.select(None);
+        } else if let Some(sel) = self.processes_state.selected() {
+            self.processes_state
+                .select(Some(sel.min(self.process_counters.len() - 1)));
+        }
+    }
+
+    pub fn add_event(&mut self, mut event: EventData) {
         if self.paused {
             return;
         }
 
+        if event.remote_addr.is_some() && (event.comm == "unknown" || event.pid == 0) {
+            self.resolve_connection_owner(&mut event);
+        }
+
+        if let Some(remote_addr) = event.remote_addr.clone() {
+            self.record_connection(&event.comm, remote_addr, event.proto.clone(), event.timestamp);
+        }
+
         self.events.push_back(event.clone());
         if self.events.len() > 1000 {
             self.events.pop_front();
@@ -80,9 +486,75 @@ impl Dashboard {
 
         *self.event_counters.entry(event.event_type.clone()).or_insert(0) += 1;
         *self.process_counters.entry(event.comm.clone()).or_insert(0) += 1;
+        if event.pid != 0 {
+            self.process_pids.insert(event.comm.clone(), event.pid);
+        }
         self.total_events += 1;
     }
 
+    /// Fill in `event.pid`/`event.comm` from the live socket table when the
+    /// agent's own event lacks them (the eBPF hook can fire before userspace
+    /// has attributed the fd to a process).
+    fn resolve_connection_owner(&mut self, event: &mut EventData) {
+        let (Some(local), Some(remote)) = (event.local_addr.as_deref(), event.remote_addr.as_deref()) else {
+            return;
+        };
+        let proto = event.proto.as_deref().unwrap_or("tcp");
+        if let Some(owner) = self.socket_table.resolve(local, remote, proto) {
+            event.pid = owner.pid;
+            event.comm = owner.comm;
+        }
+    }
+
+    /// Aggregate one outbound connection under its owning process, flagging
+    /// destinations never seen before this session.
+    fn record_connection(&mut self, comm: &str, remote_addr: String, proto: Option<String>, timestamp: u64) {
+        let proto = proto.unwrap_or_else(|| "tcp".to_string());
+        let is_new_destination = self.known_destinations.insert(remote_addr.clone());
+
+        let destinations = self.connections.entry(comm.to_string()).or_default();
+        if let Some(stat) = destinations.iter_mut().find(|s| s.remote_addr == remote_addr) {
+            stat.count += 1;
+            stat.last_seen = timestamp;
+        } else {
+            destinations.push(ConnectionStat {
+                remote_addr,
+                proto,
+                count: 1,
+                last_seen: timestamp,
+                is_new_destination,
+            });
+        }
+    }
+
+    /// Drop events older than `window_secs` and decrement their per-type/per-process
+    /// counters, so rate/ratio scoring reflects recent activity instead of the whole
+    /// session. Mirrors bottom's `STALE_MAX_SECONDS` eviction.
+    fn evict_stale_events(&mut self) {
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs();
+        while let Some(front) = self.events.front() {
+            if now.saturating_sub(front.timestamp) <= self.window_secs {
+                break;
+            }
+            let stale = self.events.pop_front().unwrap();
+
+            if let Some(count) = self.event_counters.get_mut(&stale.event_type) {
+                *count = count.saturating_sub(1);
+                if *count == 0 {
+                    self.event_counters.remove(&stale.event_type);
+                }
+            }
+            if let Some(count) = self.process_counters.get_mut(&stale.comm) {
+                *count = count.saturating_sub(1);
+                if *count == 0 {
+                    self.process_counters.remove(&stale.comm);
+                    self.process_pids.remove(&stale.comm);
+                }
+            }
+            self.total_events = self.total_events.saturating_sub(1);
+        }
+    }
+
     pub fn update_system_stats(&mut self, system: &mut System) {
         system.refresh_cpu();
         system.refresh_memory();
@@ -95,13 +567,39 @@ impl Dashboard {
         self.system_stats.processes = system.processes().len();
     }
 
+    /// Runs on the slow `update_rate` cadence: refresh system stats and sample
+    /// the threat score exactly once, so the 60s window isn't polluted by
+    /// duplicate samples from every UI redraw.
+    pub fn update(&mut self, system: &mut System) {
+        self.evict_stale_events();
+        self.update_system_stats(system);
+
+        let score = self.calculate_anomaly_score();
+        self.anomaly_scores.push_back(score);
+        if self.anomaly_scores.len() > 60 {
+            self.anomaly_scores.pop_front();
+        }
+    }
+
+    /// Events/sec over `window_secs` (or session uptime, whichever is shorter).
+    /// `total_events` only counts events still inside the sliding window (see
+    /// `evict_stale_events`), so dividing by full session uptime instead of
+    /// the window would make the rate decay toward zero under sustained load.
+    pub fn event_rate(&self) -> f64 {
+        if self.total_events == 0 {
+            return 0.0;
+        }
+        let uptime = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() - self.start_time + 1;
+        let window = uptime.min(self.window_secs).max(1);
+        self.total_events as f64 / window as f64
+    }
+
     pub fn calculate_anomaly_score(&self) -> f64 {
         if self.total_events == 0 {
             return 0.0;
         }
 
-        let rate = self.total_events as f64 / 
-            (SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() - self.start_time + 1) as f64;
+        let rate = self.event_rate();
         let exec_ratio = *self.event_counters.get("exec").unwrap_or(&0) as f64 / self.total_events as f64;
         
         let mut score: f64 = 0.0;
@@ -134,13 +632,8 @@ impl Dashboard {
 
     pub fn render(&mut self, f: &mut Frame) {
         let size = f.size();
-        
-        // Update anomaly scores
-        let current_score = self.calculate_anomaly_score();
-        self.anomaly_scores.push_back(current_score);
-        if self.anomaly_scores.len() > 60 {
-            self.anomaly_scores.pop_front();
-        }
+
+        self.clamp_selections();
 
         // Main layout
         let chunks = Layout::default()
@@ -162,6 +655,8 @@ impl Dashboard {
             2 => self.render_anomaly(f, chunks[2]),
             3 => self.render_system(f, chunks[2]),
             4 => self.render_controls(f, chunks[2]),
+            5 => self.render_policy(f, chunks[2]),
+            6 => self.render_connections(f, chunks[2]),
             _ => self.render_overview(f, chunks[2]),
         }
         
@@ -170,6 +665,10 @@ impl Dashboard {
         if self.show_help {
             self.render_help(f, size);
         }
+
+        if let Some((comm, pid)) = self.pending_kill.clone() {
+            self.render_kill_confirm(f, size, &comm, pid);
+        }
     }
 
     fn render_header(&self, f: &mut Frame, area: Rect) {
@@ -188,10 +687,12 @@ impl Dashboard {
     fn render_tabs(&self, f: &mut Frame, area: Rect) {
         let tabs = Tabs::new(vec![
             "OVERVIEW",
-            "EVENTS", 
+            "EVENTS",
             "ANOMALY DETECTION",
             "SYSTEM MONITORING",
-            "CONTROL PANEL"
+            "CONTROL PANEL",
+            "POLICY",
+            "CONNECTIONS",
         ])
         .block(Block::default().borders(Borders::ALL))
         .select(self.current_tab)
@@ -201,7 +702,7 @@ impl Dashboard {
         f.render_widget(tabs, area);
     }
 
-    fn render_overview(&self, f: &mut Frame, area: Rect) {
+    fn render_overview(&mut self, f: &mut Frame, area: Rect) {
         let chunks = Layout::default()
             .direction(Direction::Horizontal)
             .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
@@ -229,17 +730,18 @@ impl Dashboard {
 
     fn render_key_metrics(&self, f: &mut Frame, area: Rect) {
         let uptime = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() - self.start_time;
-        let rate = if uptime > 0 { self.total_events as f64 / uptime as f64 } else { 0.0 };
-        
+        let rate = self.event_rate();
+
         let total_events_str = format!("{}", self.total_events);
         let rate_str = format!("{:.1}/s", rate);
         let uptime_str = format!("{}s", uptime);
         let cpu_str = format!("{:.1}%", self.system_stats.cpu_usage);
         let memory_str = format!("{:.1}%", self.system_stats.memory_usage);
         let load_str = format!("{:.2}", self.system_stats.load_avg);
-        
+        let events_label = format!("Events ({}s window)", self.window_secs);
+
         let metrics = vec![
-            Row::new(vec!["Total Events", &total_events_str]),
+            Row::new(vec![&events_label, &total_events_str]),
             Row::new(vec!["Event Rate", &rate_str]),
             Row::new(vec!["Session Uptime", &uptime_str]),
             Row::new(vec!["CPU Usage", &cpu_str]),
@@ -276,23 +778,24 @@ impl Dashboard {
         f.render_widget(events_widget, area);
     }
 
-    fn render_top_processes(&self, f: &mut Frame, area: Rect) {
-        let mut processes: Vec<_> = self.process_counters.iter().collect();
-        processes.sort_by(|a, b| b.1.cmp(a.1));
-        
+    fn render_top_processes(&mut self, f: &mut Frame, area: Rect) {
+        let (processes, sort_label) = self.sorted_processes();
+
         let process_items: Vec<ListItem> = processes
             .iter()
-            .take(10)
-            .map(|(name, count)| {
-                ListItem::new(format!("{}: {}", name, count))
-            })
+            .map(|(name, count)| ListItem::new(format!("{}: {}", name, count)))
             .collect();
 
         let processes_widget = List::new(process_items)
-            .block(Block::default().borders(Borders::ALL).title("Top Processes"))
-            .style(Style::default().fg(Color::White));
-
-        f.render_widget(processes_widget, area);
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!("Top Processes (sorted by {})", sort_label)),
+            )
+            .style(Style::default().fg(Color::White))
+            .highlight_style(Style::default().fg(Color::Black).bg(Color::Cyan));
+
+        f.render_stateful_widget(processes_widget, area, &mut self.processes_state);
     }
 
     fn render_anomaly_overview(&self, f: &mut Frame, area: Rect) {
@@ -318,8 +821,8 @@ impl Dashboard {
             "Threat Score: {:.2}/5.0\n\
              Risk Level: {}\n\
              Trend: {}\n\
-             Analysis Window: 60s rolling",
-            current_score, risk_level, trend
+             Analysis Window: {}s rolling",
+            current_score, risk_level, trend, self.window_secs
         );
 
         let anomaly_widget = Paragraph::new(anomaly_text)
@@ -329,21 +832,27 @@ impl Dashboard {
 
         f.render_widget(anomaly_widget, area);
 
-        // Sparkline for anomaly trend
+        // Braille-marker trend chart; a u64 Sparkline would round 1.4 and 1.9 to the same bar.
         if self.anomaly_scores.len() > 1 {
-            let sparkline_area = Rect {
+            let chart_area = Rect {
                 x: area.x + 1,
                 y: area.y + 5,
-                width: area.width - 2,
+                width: area.width.saturating_sub(2),
                 height: 3,
             };
-            
-            let sparkline_data: Vec<u64> = self.anomaly_scores.iter().map(|&x| x as u64).collect();
-            let sparkline = Sparkline::default()
-                .data(&sparkline_data)
-                .style(Style::default().fg(color));
-            
-            f.render_widget(sparkline, sparkline_area);
+
+            let points = self.anomaly_score_points();
+            let dataset = Dataset::default()
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(color))
+                .data(&points);
+
+            let chart = Chart::new(vec![dataset])
+                .x_axis(Axis::default().bounds([-(self.anomaly_scores.len() as f64 - 1.0), 0.0]))
+                .y_axis(Axis::default().bounds([0.0, 5.0]));
+
+            f.render_widget(chart, chart_area);
         }
     }
 
@@ -374,17 +883,28 @@ impl Dashboard {
         f.render_widget(system_widget, area);
     }
 
-    fn render_events(&self, f: &mut Frame, area: Rect) {
+    fn render_events(&mut self, f: &mut Frame, area: Rect) {
+        let list_area = if self.search.active {
+            let chunks = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Min(0), Constraint::Length(3)])
+                .split(area);
+            self.render_search_bar(f, chunks[1]);
+            chunks[0]
+        } else {
+            area
+        };
+
         let recent_events: Vec<ListItem> = self.events
             .iter()
             .rev()
-            .take(30)
+            .filter(|event| self.matches_search(event))
             .map(|event| {
                 let timestamp = chrono::DateTime::from_timestamp(event.timestamp as i64, 0)
                     .unwrap_or_default()
                     .format("%H:%M:%S")
                     .to_string();
-                
+
                 let event_type = match event.event_type.as_str() {
                     "exec" => "EXEC",
                     "open" => "FILE",
@@ -394,7 +914,7 @@ impl Dashboard {
                     "ptrace" => "TRACE",
                     _ => &event.event_type.to_uppercase()
                 };
-                
+
                 ListItem::new(format!(
                     "{} [{}] {} (PID:{}) {}",
                     timestamp, event_type, event.comm, event.pid, event.file
@@ -402,11 +922,49 @@ impl Dashboard {
             })
             .collect();
 
+        let title = if self.search.is_invalid_search {
+            "Security Event Log (invalid search pattern)"
+        } else if !self.search.is_blank_search {
+            "Security Event Log (filtered)"
+        } else {
+            "Security Event Log"
+        };
+
         let events_widget = List::new(recent_events)
-            .block(Block::default().borders(Borders::ALL).title("Security Event Log"))
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .style(Style::default().fg(Color::White))
+            .highlight_style(Style::default().fg(Color::Black).bg(Color::Cyan));
+
+        f.render_stateful_widget(events_widget, list_area, &mut self.events_state);
+    }
+
+    fn render_search_bar(&self, f: &mut Frame, area: Rect) {
+        let (border_color, hint) = if self.search.is_invalid_search {
+            (Color::Red, "invalid regex")
+        } else if self.search.is_blank_search {
+            (Color::DarkGray, "type to filter, Esc to cancel")
+        } else {
+            (Color::Cyan, "regex/substring, Esc to cancel")
+        };
+
+        let before_cursor = &self.search.query[..self.search.cursor];
+        let after_cursor = &self.search.query[self.search.cursor..];
+        let line = Line::from(vec![
+            ratatui::text::Span::raw(format!("/{}", before_cursor)),
+            ratatui::text::Span::styled("│", Style::default().fg(Color::Yellow)),
+            ratatui::text::Span::raw(after_cursor.to_string()),
+        ]);
+
+        let search_widget = Paragraph::new(line)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(hint)
+                    .border_style(Style::default().fg(border_color)),
+            )
             .style(Style::default().fg(Color::White));
 
-        f.render_widget(events_widget, area);
+        f.render_widget(search_widget, area);
     }
 
     fn render_anomaly(&self, f: &mut Frame, area: Rect) {
@@ -428,19 +986,34 @@ impl Dashboard {
 
         f.render_widget(gauge, chunks[0]);
 
-        // Threat history chart
+        // Threat history chart (braille markers preserve sub-integer scores lost to a u64 Sparkline)
         if self.anomaly_scores.len() > 1 {
-            let sparkline_data: Vec<u64> = self.anomaly_scores.iter().map(|&x| x as u64).collect();
-            let sparkline = Sparkline::default()
-                .data(&sparkline_data)
+            let points = self.anomaly_score_points();
+            let span_secs = (self.anomaly_scores.len() as f64 - 1.0) * self.update_rate.as_secs_f64();
+            let dataset = Dataset::default()
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
                 .style(Style::default().fg(Color::Yellow))
-                .block(Block::default().borders(Borders::ALL).title("Threat Level History (60s)"));
-
-            f.render_widget(sparkline, chunks[1]);
+                .data(&points);
+
+            let chart = Chart::new(vec![dataset])
+                .block(Block::default().borders(Borders::ALL).title("Threat Level History (60s)"))
+                .x_axis(
+                    Axis::default()
+                        .bounds([-(self.anomaly_scores.len() as f64 - 1.0), 0.0])
+                        .labels(vec![Line::from(format!("-{:.0}s", span_secs)), Line::from("now")]),
+                )
+                .y_axis(
+                    Axis::default()
+                        .bounds([0.0, 5.0])
+                        .labels(vec![Line::from("0.0"), Line::from("2.5"), Line::from("5.0")]),
+                );
+
+            f.render_widget(chart, chunks[1]);
         }
     }
 
-    fn render_system(&self, f: &mut Frame, area: Rect) {
+    fn render_system(&mut self, f: &mut Frame, area: Rect) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
             .constraints([Constraint::Length(6), Constraint::Length(6), Constraint::Min(0)])
@@ -488,22 +1061,23 @@ impl Dashboard {
         f.render_widget(details_widget, chunks[1]);
 
         // Process list
-        let mut processes: Vec<_> = self.process_counters.iter().collect();
-        processes.sort_by(|a, b| b.1.cmp(a.1));
-        
+        let (processes, sort_label) = self.sorted_processes();
+
         let process_items: Vec<ListItem> = processes
             .iter()
-            .take(20)
-            .map(|(name, count)| {
-                ListItem::new(format!("{}: {}", name, count))
-            })
+            .map(|(name, count)| ListItem::new(format!("{}: {}", name, count)))
             .collect();
 
         let processes_widget = List::new(process_items)
-            .block(Block::default().borders(Borders::ALL).title("Process Activity"))
-            .style(Style::default().fg(Color::White));
-
-        f.render_widget(processes_widget, chunks[2]);
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(format!("Process Activity (sorted by {})", sort_label)),
+            )
+            .style(Style::default().fg(Color::White))
+            .highlight_style(Style::default().fg(Color::Black).bg(Color::Cyan));
+
+        f.render_stateful_widget(processes_widget, chunks[2], &mut self.processes_state);
     }
 
     fn render_controls(&self, f: &mut Frame, area: Rect) {
@@ -513,11 +1087,17 @@ impl Dashboard {
                            x - Stop Agent\n\
                            p - Pause/Resume Monitoring\n\
                            r - Reset All Statistics\n\
+                           dd - Kill selected process (confirm)\n\
+                           c - Cycle process sort column\n\
+                           v - Reverse process sort direction\n\
                            h - Toggle Help\n\
                            \n\
                            Navigation:\n\
                            Tab/Shift+Tab - Switch tabs\n\
-                           1-5 - Jump to tab\n\
+                           1-7 - Jump to tab\n\
+                           Up/Down - Move selection\n\
+                           Shift+Up/Down - Page selection\n\
+                           / - Search events (Events tab)\n\
                            \n\
                            Security Features:\n\
                            • Real-time eBPF monitoring\n\
@@ -542,19 +1122,130 @@ impl Dashboard {
         f.render_widget(controls_widget, area);
     }
 
+    fn render_policy(&self, f: &mut Frame, area: Rect) {
+        let Some(status) = &self.policy_status else {
+            let empty = Paragraph::new("No daemon reachable; policy status unavailable.")
+                .block(Block::default().borders(Borders::ALL).title("Policy"))
+                .style(Style::default().fg(Color::DarkGray));
+            f.render_widget(empty, area);
+            return;
+        };
+
+        if status.rules.is_empty() {
+            let empty = Paragraph::new("No policy applied. Use `ravn-ctl apply-policy <file>`.")
+                .block(Block::default().borders(Borders::ALL).title("Policy"))
+                .style(Style::default().fg(Color::DarkGray));
+            f.render_widget(empty, area);
+            return;
+        }
+
+        let rows: Vec<Row> = status
+            .rules
+            .iter()
+            .map(|rs| {
+                let selectors = [
+                    rs.rule.comm.as_ref().map(|v| format!("comm={v}")),
+                    rs.rule.uid.map(|v| format!("uid={v}")),
+                    rs.rule.file.as_ref().map(|v| format!("file={v}")),
+                    rs.rule.etype.as_ref().map(|v| format!("etype={v}")),
+                ]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<_>>()
+                .join(", ");
+
+                Row::new(vec![
+                    rs.rule.name.clone(),
+                    selectors,
+                    rs.rule.action.label().to_string(),
+                    rs.matches.to_string(),
+                ])
+            })
+            .collect();
+
+        let table = Table::new(
+            rows,
+            &[
+                Constraint::Percentage(20),
+                Constraint::Percentage(45),
+                Constraint::Percentage(15),
+                Constraint::Percentage(20),
+            ],
+        )
+        .header(Row::new(vec!["Rule", "Selectors", "Action", "Matches"]).style(Style::default().add_modifier(Modifier::BOLD)))
+        .block(Block::default().borders(Borders::ALL).title("Active Policy"))
+        .style(Style::default().fg(Color::White));
+
+        f.render_widget(table, area);
+    }
+
+    fn render_connections(&self, f: &mut Frame, area: Rect) {
+        if self.connections.is_empty() {
+            let empty = Paragraph::new("No network activity observed yet.")
+                .block(Block::default().borders(Borders::ALL).title("Connections"))
+                .style(Style::default().fg(Color::DarkGray));
+            f.render_widget(empty, area);
+            return;
+        }
+
+        let mut rows: Vec<Row> = Vec::new();
+        for (comm, destinations) in &self.connections {
+            for stat in destinations {
+                let flag = if stat.is_new_destination { "NEW" } else { "" };
+                let style = if stat.is_new_destination {
+                    Style::default().fg(Color::Yellow)
+                } else {
+                    Style::default().fg(Color::White)
+                };
+                rows.push(
+                    Row::new(vec![
+                        comm.clone(),
+                        stat.remote_addr.clone(),
+                        stat.proto.clone(),
+                        stat.count.to_string(),
+                        flag.to_string(),
+                    ])
+                    .style(style),
+                );
+            }
+        }
+
+        let table = Table::new(
+            rows,
+            &[
+                Constraint::Percentage(25),
+                Constraint::Percentage(35),
+                Constraint::Percentage(10),
+                Constraint::Percentage(15),
+                Constraint::Percentage(15),
+            ],
+        )
+        .header(
+            Row::new(vec!["Process", "Destination", "Proto", "Count", "Flag"])
+                .style(Style::default().add_modifier(Modifier::BOLD)),
+        )
+        .block(Block::default().borders(Borders::ALL).title("Active Connections"))
+        .style(Style::default().fg(Color::White));
+
+        f.render_widget(table, area);
+    }
+
     fn render_status_bar(&self, f: &mut Frame, area: Rect) {
-        let status_text = format!(
-            "Agent: {} | Events: {} | Uptime: {}s | Rate: {:.1}/s | CPU: {:.1}% | RAM: {:.1}%",
+        let mut status_text = format!(
+            "Agent: {} | Events ({}s window): {} | Uptime: {}s | Rate: {:.1}/s | CPU: {:.1}% | RAM: {:.1}%",
             if self.paused { "PAUSED" } else { "RUNNING" },
+            self.window_secs,
             self.total_events,
             SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() - self.start_time,
-            if SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() - self.start_time > 0 {
-                self.total_events as f64 / (SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() - self.start_time) as f64
-            } else { 0.0 },
+            self.event_rate(),
             self.system_stats.cpu_usage,
             self.system_stats.memory_usage
         );
 
+        if let Some(status) = &self.last_kill_status {
+            status_text.push_str(&format!(" | {}", status));
+        }
+
         let status = Paragraph::new(status_text)
             .block(Block::default().borders(Borders::ALL))
             .style(Style::default().fg(Color::Cyan))
@@ -567,7 +1258,9 @@ impl Dashboard {
         let help_text = "RAVN Security Platform Help\n\n\
                         Navigation:\n\
                         • Tab/Shift+Tab: Switch between tabs\n\
-                        • 1-5: Jump to specific tab\n\
+                        • 1-7: Jump to specific tab\n\
+                        • Up/Down: Move selection, Shift+Up/Down: page\n\
+                        • /: Search events (Events tab)\n\
                         • h: Toggle this help\n\
                         \n\
                         Controls:\n\
@@ -576,13 +1269,17 @@ impl Dashboard {
                         • x: Stop agent\n\
                         • p: Pause/resume monitoring\n\
                         • r: Reset statistics\n\
+                        • dd: Kill selected process (confirm)\n\
+                        • c: Cycle process sort column, v: reverse direction\n\
                         \n\
                         Tabs:\n\
                         • Overview: Key metrics and summaries\n\
                         • Events: Real-time event stream\n\
                         • Anomaly Detection: Threat analysis and trends\n\
                         • System Monitoring: System resource monitoring\n\
-                        • Control Panel: Control panel and help\n\n\
+                        • Control Panel: Control panel and help\n\
+                        • Policy: Active rules and live match counts\n\
+                        • Connections: Active sockets per process, new destinations flagged\n\n\
                         Press 'h' to close this help.";
 
         let help_widget = Paragraph::new(help_text)
@@ -600,4 +1297,48 @@ impl Dashboard {
         f.render_widget(Clear, help_area);
         f.render_widget(help_widget, help_area);
     }
+
+    fn render_kill_confirm(&self, f: &mut Frame, area: Rect, comm: &str, pid: u32) {
+        let text = format!(
+            "Terminate process?\n\n{} (PID {})\n\nPress d again to confirm, Esc to cancel",
+            comm, pid
+        );
+
+        let dialog = Paragraph::new(text)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title("Confirm Kill")
+                    .border_style(Style::default().fg(Color::Red)),
+            )
+            .style(Style::default().fg(Color::White))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+
+        let dialog_area = Rect {
+            x: area.width / 4,
+            y: area.height * 3 / 8,
+            width: area.width / 2,
+            height: area.height / 4,
+        };
+
+        f.render_widget(Clear, dialog_area);
+        f.render_widget(dialog, dialog_area);
+    }
+}
+
+/// Drains up to 256 pending lines from `rx`, parsing and feeding each into
+/// `dashboard`. Shared by the live-agent and replay ingestion paths so both
+/// go through the exact same parse/add_event behavior.
+pub fn drain_events(rx: &mut tokio::sync::mpsc::Receiver<String>, dashboard: &mut Dashboard) {
+    for _ in 0..256 {
+        match rx.try_recv() {
+            Ok(line) => {
+                if let Some(event_data) = EventData::from_json_line(&line) {
+                    dashboard.add_event(event_data);
+                }
+            }
+            Err(_) => break,
+        }
+    }
 }
@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: Apache-2.0
+//! Batched audit export: buffers parsed events and flushes them into a
+//! durable SQLite store for forensic querying after the TUI/ring buffer has
+//! moved on.
+//!
+//! Only the SQLite sink is implemented. A Postgres/TimescaleDB backend was
+//! originally scaffolded behind a feature flag but never wired up to the
+//! async pool it needs, so it was dropped rather than ship a sink that
+//! always errors — this is a scope cut, not a finished deliverable; the
+//! Postgres/TimescaleDB sink is still open and needs its own follow-up
+//! request rather than being implied done here.
+
+use anyhow::{Context, Result};
+use rusqlite::Connection;
+use std::path::PathBuf;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use crate::app_cli_dashboard::EventData;
+
+const CREATE_TABLE: &str = "\
+    CREATE TABLE IF NOT EXISTS events (\
+        ts BIGINT NOT NULL, \
+        etype TEXT NOT NULL, \
+        pid INTEGER NOT NULL, \
+        comm TEXT NOT NULL, \
+        file TEXT NOT NULL, \
+        uid INTEGER NOT NULL\
+    )";
+const CREATE_INDEX: &str = "CREATE INDEX IF NOT EXISTS idx_events_ts_etype ON events (ts, etype)";
+
+/// Where exported events are written.
+#[derive(Debug, Clone)]
+pub enum ExportSink {
+    Sqlite(PathBuf),
+}
+
+impl ExportSink {
+    /// Parse a `sink` argument like `sqlite:///var/lib/ravn/events.db` into
+    /// the matching backend.
+    pub fn parse(sink: &str) -> Result<Self> {
+        if let Some(path) = sink.strip_prefix("sqlite://") {
+            return Ok(ExportSink::Sqlite(PathBuf::from(path)));
+        }
+        if let Some(path) = sink.strip_prefix("sqlite:") {
+            return Ok(ExportSink::Sqlite(PathBuf::from(path)));
+        }
+        anyhow::bail!("unsupported export sink: {sink:?} (expected sqlite://)")
+    }
+}
+
+/// Batches parsed events and flushes them inside a single transaction, on
+/// whichever comes first: `batch` rows buffered, or `flush_interval` elapsed.
+/// Each buffered row is written by its own cached single-row `INSERT`; rows
+/// are not combined into one multi-row statement.
+pub struct Export {
+    conn: Connection,
+    buffer: Vec<EventData>,
+    batch: usize,
+    flush_interval: Duration,
+    last_flush: Instant,
+    retention_days: Option<u64>,
+}
+
+impl Export {
+    pub fn open(sink: ExportSink, batch: usize, retention_days: Option<u64>) -> Result<Self> {
+        let conn = match sink {
+            ExportSink::Sqlite(path) => {
+                Connection::open(&path).with_context(|| format!("open sqlite db at {:?}", path))?
+            }
+        };
+        conn.execute(CREATE_TABLE, [])?;
+        conn.execute(CREATE_INDEX, [])?;
+
+        Ok(Self {
+            conn,
+            buffer: Vec::with_capacity(batch),
+            batch,
+            flush_interval: Duration::from_secs(1),
+            last_flush: Instant::now(),
+            retention_days,
+        })
+    }
+
+    /// Buffer one event, flushing if the batch size or flush interval is hit.
+    pub fn push(&mut self, event: EventData) -> Result<()> {
+        self.buffer.push(event);
+        if self.buffer.len() >= self.batch || self.last_flush.elapsed() >= self.flush_interval {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    /// Flush on a timer tick even if the batch isn't full, so a quiet period
+    /// doesn't leave recent events un-persisted indefinitely.
+    pub fn tick(&mut self) -> Result<()> {
+        if !self.buffer.is_empty() && self.last_flush.elapsed() >= self.flush_interval {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    pub fn flush(&mut self) -> Result<()> {
+        if self.buffer.is_empty() {
+            self.last_flush = Instant::now();
+            return Ok(());
+        }
+
+        let tx = self.conn.transaction()?;
+        {
+            let mut stmt = tx.prepare_cached(
+                "INSERT INTO events (ts, etype, pid, comm, file, uid) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            )?;
+            for event in &self.buffer {
+                stmt.execute(rusqlite::params![
+                    event.timestamp as i64,
+                    event.event_type,
+                    event.pid,
+                    event.comm,
+                    event.file,
+                    event.uid,
+                ])?;
+            }
+        }
+        tx.commit()?;
+
+        self.buffer.clear();
+        self.last_flush = Instant::now();
+        Ok(())
+    }
+
+    /// Delete rows older than `retention_days`, if configured. Callers run this
+    /// periodically (e.g. once a minute), not on every flush.
+    pub fn apply_retention(&self) -> Result<()> {
+        let Some(days) = self.retention_days else {
+            return Ok(());
+        };
+        let cutoff = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            .saturating_sub(days * 86_400) as i64;
+        self.conn
+            .execute("DELETE FROM events WHERE ts < ?1", rusqlite::params![cutoff])?;
+        Ok(())
+    }
+}
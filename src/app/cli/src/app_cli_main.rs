@@ -1,15 +1,21 @@
 // SPDX-License-Identifier: Apache-2.0
-use anyhow::{bail, Context, Result};
+use anyhow::{Context, Result};
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
-use tokio::io::{self, AsyncBufReadExt};
-use tokio::process::Command;
-use serde_json;
+use tokio::io;
 use sysinfo::{System, SystemExt};
-use std::time::{SystemTime, UNIX_EPOCH};
 
+mod app_cli_bench;
 mod app_cli_dashboard;
-use app_cli_dashboard::{Dashboard, EventData};
+mod app_cli_export;
+mod app_cli_policy;
+mod app_cli_record;
+mod app_cli_sockets;
+mod app_cli_supervisor;
+use app_cli_dashboard::{Dashboard, EventData, Panel};
+use app_cli_export::{Export, ExportSink};
+use app_cli_policy::Policy;
+use app_cli_supervisor::ControlRequest;
 
 #[derive(Parser, Debug)]
 #[command(name = "ravn-ctl", version, about = "Control ravn agent")] 
@@ -23,46 +29,155 @@ enum Commands {
     Start,
     Stop,
     Tail,
-    ApplyPolicy { file: PathBuf },
+    ApplyPolicy {
+        file: PathBuf,
+        /// Evaluate against the daemon's recent-event buffer and report match
+        /// counts without making the policy active.
+        #[arg(long)]
+        dry_run: bool,
+    },
+    /// Stream live events into a durable audit store for forensic querying.
+    Export {
+        /// e.g. `sqlite:///var/lib/ravn/events.db`
+        sink: String,
+        #[arg(long, default_value_t = 256)]
+        batch: usize,
+        /// Delete rows older than N days; checked once a minute.
+        #[arg(long)]
+        retention: Option<u64>,
+    },
+    /// Record the live agent's raw event stream to a file for later replay.
+    Record { out: PathBuf },
+    /// Replay a synthetic workload through the ingestion pipeline headlessly
+    /// and report throughput/latency/drop stats as JSON, for comparing runs
+    /// across commits.
+    Bench {
+        /// JSON file describing one or more load phases.
+        workload: PathBuf,
+    },
+    /// Replay a recording captured with `Record` into the dashboard.
     #[cfg(feature = "tui")]
-    Dashboard,
+    Replay {
+        file: PathBuf,
+        #[arg(long, default_value_t = 1.0)]
+        speed: f64,
+        #[arg(long, default_value_t = 250)]
+        tick_ms: u64,
+        #[arg(long, default_value_t = 1000)]
+        update_ms: u64,
+        #[arg(long, default_value_t = 60)]
+        window_secs: u64,
+    },
+    #[cfg(feature = "tui")]
+    Dashboard {
+        /// UI redraw/input-poll cadence in milliseconds.
+        #[arg(long, default_value_t = 250)]
+        tick_ms: u64,
+        /// System-stats/anomaly-scoring cadence in milliseconds.
+        #[arg(long, default_value_t = 1000)]
+        update_ms: u64,
+        /// Sliding window (seconds) for event/process counters and rate scoring.
+        #[arg(long, default_value_t = 60)]
+        window_secs: u64,
+    },
+    /// Internal: runs as the daemon itself, owning the agent child and
+    /// serving the control socket. Spawned by `Start`, not meant to be
+    /// invoked directly.
+    #[command(hide = true, name = "supervisor-daemon")]
+    SupervisorDaemon { agent: PathBuf },
 }
 
 async fn start_agent() -> Result<()> {
-    let agent = PathBuf::from("artifacts/ravn");
-    if !agent.exists() { bail!("agent binary not found at {:?}", agent); }
-    Command::new(agent).spawn().context("spawn agent")?;
-    Ok(())
+    app_cli_supervisor::start_agent().await
 }
 
 async fn stop_agent() -> Result<()> {
-    // naive: killall ravn if available
-    let _ = Command::new("pkill").arg("-f").arg("/ravn$").status().await;
-    Ok(())
+    app_cli_supervisor::stop_agent().await
 }
 
 async fn tail_logs() -> Result<()> {
-    // For MVP, run agent in foreground and show stdout
-    let agent = PathBuf::from("artifacts/ravn");
-    if !agent.exists() { bail!("agent binary not found at {:?}", agent); }
-    let mut child = Command::new(agent).stdout(std::process::Stdio::piped()).spawn()?;
-    let stdout = child.stdout.take().context("take stdout")?;
-    let mut lines = io::BufReader::new(stdout).lines();
-    while let Some(line) = lines.next_line().await? { println!("{}", line); }
+    let mut rx = app_cli_supervisor::subscribe().await?;
+    while let Some(line) = rx.recv().await {
+        println!("{}", line);
+    }
+    Ok(())
+}
+
+async fn export_events(sink: String, batch: usize, retention: Option<u64>) -> Result<()> {
+    let sink = ExportSink::parse(&sink)?;
+    let mut export = Export::open(sink, batch, retention)?;
+
+    // Attach to the shared daemon like `Tail`/`Dashboard` instead of spawning
+    // another copy of the agent, so the daemon's active policy (including
+    // `drop` rules) applies to what this sink stores, instead of a second,
+    // unmanaged agent nobody is watching.
+    let mut rx = app_cli_supervisor::subscribe().await?;
+
+    let mut flush_tick = tokio::time::interval(std::time::Duration::from_secs(1));
+    let mut retention_check = tokio::time::interval(std::time::Duration::from_secs(60));
+    loop {
+        tokio::select! {
+            line = rx.recv() => {
+                match line {
+                    Some(line) => {
+                        if let Some(event) = EventData::from_json_line(&line) {
+                            export.push(event)?;
+                        }
+                    }
+                    None => break,
+                }
+            }
+            _ = flush_tick.tick() => {
+                export.tick()?;
+            }
+            _ = retention_check.tick() => {
+                export.apply_retention()?;
+            }
+        }
+    }
+
+    export.flush()?;
+    Ok(())
+}
+
+async fn run_bench(workload: PathBuf) -> Result<()> {
+    let workload = app_cli_bench::Workload::from_file(&workload).await?;
+    let report = app_cli_bench::run(workload).await?;
+    println!("{}", serde_json::to_string_pretty(&report)?);
     Ok(())
 }
 
-async fn apply_policy(file: PathBuf) -> Result<()> {
-    let content = tokio::fs::read(&file).await.context("read policy")?;
-    let _value: serde_yaml::Value = serde_yaml::from_slice(&content).context("parse policy")?;
-    println!("policy applied from {:?}", file);
+async fn apply_policy(file: PathBuf, dry_run: bool) -> Result<()> {
+    let content = tokio::fs::read_to_string(&file).await.context("read policy")?;
+    let policy = Policy::from_yaml(&content)?;
+
+    if dry_run {
+        let status = app_cli_supervisor::dry_run_policy(policy).await?;
+        println!("dry run against recent daemon events ({} rules):", status.rules.len());
+        for rule in &status.rules {
+            println!("  {} ({}): {} matches", rule.rule.name, rule.rule.action.label(), rule.matches);
+        }
+    } else {
+        app_cli_supervisor::apply_policy(policy).await?;
+        println!("policy applied from {:?}", file);
+    }
     Ok(())
 }
 
+/// Point keyboard navigation at whichever panel is scrollable on the active tab.
+#[cfg(feature = "tui")]
+fn sync_focused_panel(dashboard: &mut Dashboard) {
+    dashboard.focused_panel = match dashboard.current_tab {
+        1 => Panel::Events,
+        0 | 3 => Panel::Processes,
+        _ => dashboard.focused_panel,
+    };
+}
+
 #[cfg(feature = "tui")]
-async fn dashboard() -> Result<()> {
+async fn dashboard(tick_ms: u64, update_ms: u64, window_secs: u64) -> Result<()> {
     use crossterm::{
-        event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode},
+        event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers},
         execute,
         terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
     };
@@ -71,27 +186,12 @@ async fn dashboard() -> Result<()> {
         Terminal,
     };
     use std::io;
-    use tokio::sync::mpsc;
+    use std::time::Instant;
 
-    // Spawn agent in daemon mode
-    let mut child = Command::new("sudo")
-        .arg("/home/hack/projects/ravn/artifacts/ravn")
-        .arg("-d")
-        .arg("-v")
-        .stdout(std::process::Stdio::piped())
-        .stderr(std::process::Stdio::piped())
-        .spawn()
-        .context("spawn agent")?;
-
-    let (tx, mut rx) = mpsc::channel::<String>(1024);
-    let stdout = child.stdout.take().context("take stdout")?;
-    let mut reader = tokio::io::BufReader::new(stdout).lines();
-    tokio::spawn(async move {
-        while let Ok(Some(line)) = reader.next_line().await { 
-            // Don't filter out ravn's own events - we want to see all events
-            let _ = tx.send(line).await; 
-        }
-    });
+    // Make sure a daemon is running, then attach to its event stream. Other
+    // clients (another dashboard, `tail`) can subscribe to the same daemon.
+    app_cli_supervisor::start_agent().await?;
+    let mut rx = app_cli_supervisor::subscribe().await?;
 
     // Setup terminal
     enable_raw_mode()?;
@@ -101,99 +201,72 @@ async fn dashboard() -> Result<()> {
     let mut terminal = Terminal::new(backend)?;
 
     // Initialize dashboard and system monitoring
-    let mut dashboard = Dashboard::new();
+    let mut dashboard = Dashboard::new(tick_ms, update_ms, window_secs);
+    sync_focused_panel(&mut dashboard);
     let mut system = System::new_all();
-    
+
     let mut running = true;
+    let mut last_update = Instant::now() - dashboard.update_rate;
 
     while running {
         // Process incoming events
-        for _ in 0..256 {
-            match rx.try_recv() {
-                Ok(line) => {
-                    // Parse JSON event
-                    if let Ok(event) = serde_json::from_str::<serde_json::Value>(&line) {
-                        let event_data = EventData {
-                            timestamp: SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs(),
-                            event_type: event.get("etype").and_then(|v| v.as_str()).unwrap_or("unknown").to_string(),
-                            pid: event.get("pid").and_then(|v| v.as_u64()).unwrap_or(0) as u32,
-                            comm: event.get("comm").and_then(|v| v.as_str()).unwrap_or("unknown").to_string(),
-                            file: event.get("file").and_then(|v| v.as_str()).unwrap_or("").to_string(),
-                            uid: event.get("uid").and_then(|v| v.as_u64()).unwrap_or(0) as u32,
-                        };
-                        dashboard.add_event(event_data);
-                    }
-                }
-                Err(_) => break,
-            }
-        }
+        app_cli_dashboard::drain_events(&mut rx, &mut dashboard);
 
-        // Update system stats
-        dashboard.update_system_stats(&mut system);
+        // Update system stats + anomaly score on the slow cadence only, so the
+        // 60s window isn't sampled once per UI tick.
+        if last_update.elapsed() >= dashboard.update_rate {
+            dashboard.update(&mut system);
+            dashboard.policy_status = app_cli_supervisor::policy_status().await.ok();
+            last_update = Instant::now();
+        }
 
-        // Render dashboard
+        // Render dashboard every UI tick so input stays responsive.
         terminal.draw(|f| {
             dashboard.render(f);
         })?;
 
         // Handle input
-        if event::poll(std::time::Duration::from_millis(100))? {
+        if event::poll(dashboard.tick_rate)? {
             if let Event::Key(k) = event::read()? {
+                if dashboard.search.active {
+                    match k.code {
+                        KeyCode::Esc => dashboard.exit_search(),
+                        KeyCode::Enter => dashboard.exit_search(),
+                        KeyCode::Backspace => dashboard.search_backspace(),
+                        KeyCode::Left => dashboard.search_cursor_left(),
+                        KeyCode::Right => dashboard.search_cursor_right(),
+                        KeyCode::Char(c) => dashboard.search_push_char(c),
+                        _ => {}
+                    }
+                    continue;
+                }
+                if dashboard.pending_kill.is_some() {
+                    match k.code {
+                        KeyCode::Char('d') => dashboard.confirm_kill(),
+                        _ => dashboard.cancel_kill(),
+                    }
+                    continue;
+                }
                 match k.code {
                     KeyCode::Char('q') => running = false,
+                    KeyCode::Char('/') if dashboard.current_tab == 1 => dashboard.enter_search(),
+                    KeyCode::Char('d') => dashboard.request_kill(),
+                    KeyCode::Char('c') => dashboard.cycle_sort_key(),
+                    KeyCode::Char('v') => dashboard.toggle_sort_direction(),
                     KeyCode::Char('h') => dashboard.show_help = !dashboard.show_help,
                     KeyCode::Char('s') => {
-                        // Restart agent
-                        let _ = child.start_kill();
-                        child = Command::new("sudo")
-                            .arg("/home/hack/projects/ravn/artifacts/ravn")
-                            .arg("-d")
-                            .arg("-v")
-                            .stdout(std::process::Stdio::piped())
-                            .stderr(std::process::Stdio::piped())
-                            .spawn()
-                            .context("restart agent")?;
-                        
-                        // Restart event processing pipeline
-                        let (new_tx, new_rx) = mpsc::channel::<String>(1024);
-                        let stdout = child.stdout.take().context("take stdout")?;
-                        let mut reader = tokio::io::BufReader::new(stdout).lines();
-                        tokio::spawn(async move {
-                            while let Ok(Some(line)) = reader.next_line().await { 
-                                let _ = new_tx.send(line).await; 
-                            }
-                        });
-                        rx = new_rx;
+                        // Restart the daemon (and the agent it owns), then
+                        // re-attach to its fresh event stream.
+                        app_cli_supervisor::stop_agent().await?;
+                        app_cli_supervisor::start_agent().await?;
+                        rx = app_cli_supervisor::subscribe().await?;
                     },
                     KeyCode::Char('x') => {
-                        let _ = child.start_kill();
+                        app_cli_supervisor::stop_agent().await?;
                     },
                     KeyCode::Char('p') => {
-                        if dashboard.paused {
-                            // Resume - restart agent
-                            child = Command::new("sudo")
-                                .arg("/home/hack/projects/ravn/artifacts/ravn")
-                                .arg("-d")
-                                .arg("-v")
-                                .stdout(std::process::Stdio::piped())
-                                .stderr(std::process::Stdio::piped())
-                                .spawn()
-                                .context("resume agent")?;
-                            
-                            // Restart event processing pipeline
-                            let (new_tx, new_rx) = mpsc::channel::<String>(1024);
-                            let stdout = child.stdout.take().context("take stdout")?;
-                            let mut reader = tokio::io::BufReader::new(stdout).lines();
-                            tokio::spawn(async move {
-                                while let Ok(Some(line)) = reader.next_line().await { 
-                                    let _ = new_tx.send(line).await; 
-                                }
-                            });
-                            rx = new_rx;
-                        } else {
-                            // Pause - stop agent
-                            let _ = child.start_kill();
-                        }
+                        let req = if dashboard.paused { ControlRequest::Resume } else { ControlRequest::Pause };
+                        app_cli_supervisor::send_control(req).await?;
                         dashboard.paused = !dashboard.paused;
                     },
                     KeyCode::Char('r') => {
@@ -202,32 +275,156 @@ async fn dashboard() -> Result<()> {
                         dashboard.event_counters.clear();
                         dashboard.process_counters.clear();
                         dashboard.anomaly_scores.clear();
+                        dashboard.connections.clear();
                         dashboard.total_events = 0;
                     },
                     KeyCode::Tab => {
-                        dashboard.current_tab = (dashboard.current_tab + 1) % 5;
+                        dashboard.current_tab = (dashboard.current_tab + 1) % 7;
+                        sync_focused_panel(&mut dashboard);
                     },
                     KeyCode::BackTab => {
-                        dashboard.current_tab = if dashboard.current_tab == 0 { 4 } else { dashboard.current_tab - 1 };
+                        dashboard.current_tab = if dashboard.current_tab == 0 { 6 } else { dashboard.current_tab - 1 };
+                        sync_focused_panel(&mut dashboard);
                     },
-                    KeyCode::Char('1') => dashboard.current_tab = 0,
-                    KeyCode::Char('2') => dashboard.current_tab = 1,
-                    KeyCode::Char('3') => dashboard.current_tab = 2,
-                    KeyCode::Char('4') => dashboard.current_tab = 3,
-                    KeyCode::Char('5') => dashboard.current_tab = 4,
+                    KeyCode::Char('1') => { dashboard.current_tab = 0; sync_focused_panel(&mut dashboard); },
+                    KeyCode::Char('2') => { dashboard.current_tab = 1; sync_focused_panel(&mut dashboard); },
+                    KeyCode::Char('3') => { dashboard.current_tab = 2; sync_focused_panel(&mut dashboard); },
+                    KeyCode::Char('4') => { dashboard.current_tab = 3; sync_focused_panel(&mut dashboard); },
+                    KeyCode::Char('5') => { dashboard.current_tab = 4; sync_focused_panel(&mut dashboard); },
+                    KeyCode::Char('6') => { dashboard.current_tab = 5; sync_focused_panel(&mut dashboard); },
+                    KeyCode::Char('7') => { dashboard.current_tab = 6; sync_focused_panel(&mut dashboard); },
+                    KeyCode::Up if k.modifiers.contains(KeyModifiers::SHIFT) => dashboard.on_page_up(),
+                    KeyCode::Down if k.modifiers.contains(KeyModifiers::SHIFT) => dashboard.on_page_down(),
+                    KeyCode::Up => dashboard.on_up(),
+                    KeyCode::Down => dashboard.on_down(),
                     _ => {}
                 }
             }
         }
     }
 
-    // Cleanup
+    // Cleanup. Leave the daemon running for other clients (`tail`, another
+    // dashboard) — use `ravn-ctl stop` to tear it down explicitly.
     disable_raw_mode()?;
     execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
-    let _ = child.start_kill();
     Ok(())
 }
 
+/// Same rendering/ingestion path as `dashboard()`, but fed from a recorded
+/// file instead of a live agent. There is no daemon to restart/pause, so
+/// `s`/`x`/`p` are no-ops here.
+#[cfg(feature = "tui")]
+async fn replay_dashboard(file: PathBuf, speed: f64, tick_ms: u64, update_ms: u64, window_secs: u64) -> Result<()> {
+    use crossterm::{
+        event::{self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyModifiers},
+        execute,
+        terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+    };
+    use ratatui::{
+        backend::CrosstermBackend,
+        Terminal,
+    };
+    use std::io;
+    use std::time::Instant;
+    use tokio::sync::mpsc;
+
+    let (tx, mut rx) = mpsc::channel::<String>(1024);
+    tokio::spawn(async move {
+        if let Err(e) = app_cli_record::replay_into(file, speed, tx).await {
+            eprintln!("replay error: {e:#}");
+        }
+    });
+
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    let backend = CrosstermBackend::new(stdout);
+    let mut terminal = Terminal::new(backend)?;
+
+    let mut dashboard = Dashboard::new(tick_ms, update_ms, window_secs);
+    sync_focused_panel(&mut dashboard);
+    let mut system = System::new_all();
+
+    let mut running = true;
+    let mut last_update = Instant::now() - dashboard.update_rate;
+
+    while running {
+        app_cli_dashboard::drain_events(&mut rx, &mut dashboard);
+
+        if last_update.elapsed() >= dashboard.update_rate {
+            dashboard.update(&mut system);
+            last_update = Instant::now();
+        }
+
+        terminal.draw(|f| {
+            dashboard.render(f);
+        })?;
+
+        if event::poll(dashboard.tick_rate)? {
+            if let Event::Key(k) = event::read()? {
+                if dashboard.search.active {
+                    match k.code {
+                        KeyCode::Esc => dashboard.exit_search(),
+                        KeyCode::Enter => dashboard.exit_search(),
+                        KeyCode::Backspace => dashboard.search_backspace(),
+                        KeyCode::Left => dashboard.search_cursor_left(),
+                        KeyCode::Right => dashboard.search_cursor_right(),
+                        KeyCode::Char(c) => dashboard.search_push_char(c),
+                        _ => {}
+                    }
+                    continue;
+                }
+                if dashboard.pending_kill.is_some() {
+                    match k.code {
+                        KeyCode::Char('d') => dashboard.confirm_kill(),
+                        _ => dashboard.cancel_kill(),
+                    }
+                    continue;
+                }
+                match k.code {
+                    KeyCode::Char('q') => running = false,
+                    KeyCode::Char('/') if dashboard.current_tab == 1 => dashboard.enter_search(),
+                    KeyCode::Char('d') => dashboard.request_kill(),
+                    KeyCode::Char('c') => dashboard.cycle_sort_key(),
+                    KeyCode::Char('v') => dashboard.toggle_sort_direction(),
+                    KeyCode::Char('h') => dashboard.show_help = !dashboard.show_help,
+                    KeyCode::Char('r') => {
+                        dashboard.events.clear();
+                        dashboard.event_counters.clear();
+                        dashboard.process_counters.clear();
+                        dashboard.anomaly_scores.clear();
+                        dashboard.connections.clear();
+                        dashboard.total_events = 0;
+                    },
+                    KeyCode::Tab => {
+                        dashboard.current_tab = (dashboard.current_tab + 1) % 7;
+                        sync_focused_panel(&mut dashboard);
+                    },
+                    KeyCode::BackTab => {
+                        dashboard.current_tab = if dashboard.current_tab == 0 { 6 } else { dashboard.current_tab - 1 };
+                        sync_focused_panel(&mut dashboard);
+                    },
+                    KeyCode::Char('1') => { dashboard.current_tab = 0; sync_focused_panel(&mut dashboard); },
+                    KeyCode::Char('2') => { dashboard.current_tab = 1; sync_focused_panel(&mut dashboard); },
+                    KeyCode::Char('3') => { dashboard.current_tab = 2; sync_focused_panel(&mut dashboard); },
+                    KeyCode::Char('4') => { dashboard.current_tab = 3; sync_focused_panel(&mut dashboard); },
+                    KeyCode::Char('5') => { dashboard.current_tab = 4; sync_focused_panel(&mut dashboard); },
+                    KeyCode::Char('6') => { dashboard.current_tab = 5; sync_focused_panel(&mut dashboard); },
+                    KeyCode::Char('7') => { dashboard.current_tab = 6; sync_focused_panel(&mut dashboard); },
+                    KeyCode::Up if k.modifiers.contains(KeyModifiers::SHIFT) => dashboard.on_page_up(),
+                    KeyCode::Down if k.modifiers.contains(KeyModifiers::SHIFT) => dashboard.on_page_down(),
+                    KeyCode::Up => dashboard.on_up(),
+                    KeyCode::Down => dashboard.on_down(),
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    disable_raw_mode()?;
+    execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
+    Ok(())
+}
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -236,9 +433,19 @@ async fn main() -> Result<()> {
         Commands::Start => start_agent().await?,
         Commands::Stop => stop_agent().await?,
         Commands::Tail => tail_logs().await?,
-        Commands::ApplyPolicy { file } => apply_policy(file).await?,
+        Commands::ApplyPolicy { file, dry_run } => apply_policy(file, dry_run).await?,
+        Commands::Export { sink, batch, retention } => export_events(sink, batch, retention).await?,
+        Commands::Record { out } => app_cli_record::record(out).await?,
+        Commands::Bench { workload } => run_bench(workload).await?,
         #[cfg(feature = "tui")]
-        Commands::Dashboard => dashboard().await?,
+        Commands::Replay { file, speed, tick_ms, update_ms, window_secs } => {
+            replay_dashboard(file, speed, tick_ms, update_ms, window_secs).await?
+        }
+        #[cfg(feature = "tui")]
+        Commands::Dashboard { tick_ms, update_ms, window_secs } => {
+            dashboard(tick_ms, update_ms, window_secs).await?
+        }
+        Commands::SupervisorDaemon { agent } => app_cli_supervisor::run_supervisor(agent).await?,
     }
     Ok(())
 }
@@ -0,0 +1,186 @@
+// SPDX-License-Identifier: Apache-2.0
+//! Typed event policy: allow/deny rules keyed on `comm`/`uid`/`file`/`etype`,
+//! pushed to the running daemon over the control socket so changes take
+//! effect without restarting the agent.
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+use crate::app_cli_dashboard::EventData;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PolicyAction {
+    /// Forward the event as usual and write a `[policy alert]` line to the
+    /// daemon's stderr. A daemon launched by `start_agent` has its stderr set
+    /// to /dev/null, so that line is discarded.
+    Alert,
+    /// Don't forward the event to any subscriber (dashboard, tail, export, record);
+    /// only the per-rule match counter and the daemon's dry-run buffer see it.
+    Drop,
+    /// Forward normally; the rule exists purely to track match counts.
+    Log,
+}
+
+impl PolicyAction {
+    pub fn label(&self) -> &'static str {
+        match self {
+            PolicyAction::Alert => "alert",
+            PolicyAction::Drop => "drop",
+            PolicyAction::Log => "log",
+        }
+    }
+}
+
+/// One rule: every selector present must match (AND), any selector left out
+/// is ignored. Rules are evaluated in order and the first match wins.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyRule {
+    pub name: String,
+    #[serde(default)]
+    pub comm: Option<String>,
+    #[serde(default)]
+    pub uid: Option<u32>,
+    #[serde(default)]
+    pub file: Option<String>,
+    #[serde(default)]
+    pub etype: Option<String>,
+    pub action: PolicyAction,
+}
+
+impl PolicyRule {
+    fn matches(&self, event: &EventData) -> bool {
+        if let Some(comm) = &self.comm {
+            if !glob_match(comm, &event.comm) {
+                return false;
+            }
+        }
+        if let Some(uid) = self.uid {
+            if uid != event.uid {
+                return false;
+            }
+        }
+        if let Some(file) = &self.file {
+            if !glob_match(file, &event.file) {
+                return false;
+            }
+        }
+        if let Some(etype) = &self.etype {
+            if etype != &event.event_type {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Policy {
+    #[serde(default)]
+    pub rules: Vec<PolicyRule>,
+}
+
+impl Policy {
+    pub fn from_yaml(content: &str) -> Result<Self> {
+        let policy: Policy = serde_yaml::from_str(content).context("parse policy YAML")?;
+        policy.validate()?;
+        Ok(policy)
+    }
+
+    /// Requires at least one rule, each with at least one selector and a unique name.
+    pub fn validate(&self) -> Result<()> {
+        if self.rules.is_empty() {
+            bail!("policy must define at least one rule");
+        }
+        let mut seen_names = HashSet::new();
+        for rule in &self.rules {
+            if rule.comm.is_none() && rule.uid.is_none() && rule.file.is_none() && rule.etype.is_none() {
+                bail!("rule {:?} has no selectors (comm/uid/file/etype)", rule.name);
+            }
+            if !seen_names.insert(rule.name.clone()) {
+                bail!("duplicate rule name {:?}", rule.name);
+            }
+        }
+        Ok(())
+    }
+
+    /// Index of the first rule that matches `event`, if any.
+    pub fn matching_rule(&self, event: &EventData) -> Option<usize> {
+        self.rules.iter().position(|rule| rule.matches(event))
+    }
+
+    /// Per-rule match counts against `events`, same order as `self.rules`.
+    pub fn evaluate(&self, events: &[EventData]) -> Vec<usize> {
+        let mut counts = vec![0usize; self.rules.len()];
+        for event in events {
+            if let Some(idx) = self.matching_rule(event) {
+                counts[idx] += 1;
+            }
+        }
+        counts
+    }
+}
+
+/// Minimal shell-style glob (`*` = any run of characters, everything else
+/// literal) — `comm`/`file` selectors don't need a full glob crate for one
+/// wildcard character.
+///
+/// Iterative two-pointer match (track the last `*` and resume from there on a
+/// mismatch) instead of the naive recursive backtracker: this rule is
+/// evaluated against every incoming event in the daemon's hot read loop, and
+/// the recursive version is exponential on patterns with several `*`
+/// segments that don't match — one sloppy operator-authored rule shouldn't be
+/// able to stall ingestion for every subscriber.
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let p = pattern.as_bytes();
+    let t = text.as_bytes();
+    let (mut pi, mut ti) = (0usize, 0usize);
+    let mut star: Option<usize> = None;
+    let mut star_match = 0usize;
+
+    while ti < t.len() {
+        if pi < p.len() && p[pi] == b'*' {
+            star = Some(pi);
+            star_match = ti;
+            pi += 1;
+        } else if pi < p.len() && p[pi] == t[ti] {
+            pi += 1;
+            ti += 1;
+        } else if let Some(si) = star {
+            pi = si + 1;
+            star_match += 1;
+            ti = star_match;
+        } else {
+            return false;
+        }
+    }
+    while pi < p.len() && p[pi] == b'*' {
+        pi += 1;
+    }
+    pi == p.len()
+}
+
+/// One rule paired with its live match count, for status reporting.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PolicyRuleStatus {
+    pub rule: PolicyRule,
+    pub matches: usize,
+}
+
+/// Snapshot of the active (or dry-run) policy and how often each rule has matched.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PolicyStatus {
+    pub rules: Vec<PolicyRuleStatus>,
+}
+
+pub fn status_from(policy: &Policy, counts: Vec<usize>) -> PolicyStatus {
+    let rules = policy
+        .rules
+        .iter()
+        .cloned()
+        .zip(counts)
+        .map(|(rule, matches)| PolicyRuleStatus { rule, matches })
+        .collect();
+    PolicyStatus { rules }
+}
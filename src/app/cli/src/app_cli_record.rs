@@ -0,0 +1,71 @@
+// SPDX-License-Identifier: Apache-2.0
+//! Record raw agent stdout to a timestamped file and replay it later, so a
+//! session can be re-watched in the dashboard without a live agent attached.
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use tokio::io::AsyncWriteExt;
+use tokio::sync::mpsc;
+
+use crate::app_cli_supervisor;
+
+/// One recorded line, prefixed with its delay (in ms) since the first event.
+#[derive(Debug, Serialize, Deserialize)]
+struct RecordedLine {
+    rel_ms: u64,
+    line: String,
+}
+
+/// Attach to the shared daemon (like `Tail`/`Dashboard`) and append each line
+/// to `out`, preserving original inter-event timing via a `rel_ms` header.
+pub async fn record(out: PathBuf) -> Result<()> {
+    let mut rx = app_cli_supervisor::subscribe().await?;
+
+    let mut file = tokio::fs::File::create(&out).await.context("create recording file")?;
+    let mut first_event_at: Option<Instant> = None;
+
+    while let Some(line) = rx.recv().await {
+        let start = *first_event_at.get_or_insert_with(Instant::now);
+        let recorded = RecordedLine {
+            rel_ms: start.elapsed().as_millis() as u64,
+            line,
+        };
+        let mut json = serde_json::to_string(&recorded)?;
+        json.push('\n');
+        file.write_all(json.as_bytes()).await?;
+    }
+
+    Ok(())
+}
+
+/// Read a recording and feed its lines into `tx` at original (or
+/// `speed`-accelerated) pace, matching the timing `record` captured.
+pub async fn replay_into(file: PathBuf, speed: f64, tx: mpsc::Sender<String>) -> Result<()> {
+    if speed <= 0.0 {
+        bail!("replay speed must be > 0");
+    }
+
+    let content = tokio::fs::read_to_string(&file).await.context("read recording")?;
+    let mut last_rel_ms = 0u64;
+
+    for line in content.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let recorded: RecordedLine = serde_json::from_str(line).context("parse recorded line")?;
+
+        let delta_ms = recorded.rel_ms.saturating_sub(last_rel_ms);
+        last_rel_ms = recorded.rel_ms;
+        if delta_ms > 0 {
+            tokio::time::sleep(Duration::from_millis((delta_ms as f64 / speed) as u64)).await;
+        }
+
+        if tx.send(recorded.line).await.is_err() {
+            break;
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,161 @@
+// SPDX-License-Identifier: Apache-2.0
+//! Minimal netstat2-style socket table: walks `/proc/net/{tcp,tcp6,udp,udp6}`
+//! and `/proc/<pid>/fd` to resolve which process owns a local/remote address
+//! pair, for enriching network events that arrive without a pid/comm (eBPF
+//! socket hooks sometimes fire before userspace has the fd attributed).
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// The process that owns a resolved socket.
+#[derive(Debug, Clone)]
+pub struct SocketOwner {
+    pub pid: u32,
+    pub comm: String,
+}
+
+type SocketKey = (String, String, String);
+
+/// Caches the last `/proc/net/*` + `/proc/<pid>/fd` walk. `resolve` only ever
+/// reads this cache; the walk itself — a synchronous scan of every process's
+/// fd table — runs on a blocking-pool thread via `spawn_blocking` so it never
+/// stalls the task driving `terminal.draw`/input polling, no matter how long
+/// a busy host makes a full `/proc` walk take.
+pub struct SocketTable {
+    refresh_interval: Duration,
+    owners: Arc<Mutex<HashMap<SocketKey, SocketOwner>>>,
+    last_refresh: Arc<Mutex<Option<Instant>>>,
+    /// Set while a background refresh is in flight, so a stale cache doesn't
+    /// spawn a new blocking task on every single event in the meantime.
+    refreshing: Arc<AtomicBool>,
+}
+
+impl SocketTable {
+    pub fn new(refresh_interval: Duration) -> Self {
+        Self {
+            refresh_interval,
+            owners: Arc::new(Mutex::new(HashMap::new())),
+            last_refresh: Arc::new(Mutex::new(None)),
+            refreshing: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Resolve the (pid, comm) owning `(local_addr, remote_addr, proto)` from
+    /// the last-refreshed snapshot. Never blocks: if the snapshot is stale,
+    /// kicks off a background refresh and answers from whatever's cached
+    /// (possibly `None` on the very first call before any refresh lands).
+    pub fn resolve(&self, local_addr: &str, remote_addr: &str, proto: &str) -> Option<SocketOwner> {
+        self.maybe_spawn_refresh();
+        let owners = self.owners.lock().unwrap();
+        owners
+            .get(&(local_addr.to_string(), remote_addr.to_string(), proto.to_string()))
+            .cloned()
+    }
+
+    fn maybe_spawn_refresh(&self) {
+        let stale = match *self.last_refresh.lock().unwrap() {
+            Some(at) => at.elapsed() >= self.refresh_interval,
+            None => true,
+        };
+        if !stale {
+            return;
+        }
+        if self.refreshing.swap(true, Ordering::SeqCst) {
+            return; // a refresh is already in flight
+        }
+
+        let owners = Arc::clone(&self.owners);
+        let last_refresh = Arc::clone(&self.last_refresh);
+        let refreshing = Arc::clone(&self.refreshing);
+        tokio::task::spawn_blocking(move || {
+            let fresh = Self::enumerate();
+            *owners.lock().unwrap() = fresh;
+            *last_refresh.lock().unwrap() = Some(Instant::now());
+            refreshing.store(false, Ordering::SeqCst);
+        });
+    }
+
+    /// Build inode -> (local, remote, proto) from `/proc/net/{tcp,tcp6,udp,udp6}`,
+    /// then pid -> inode from `/proc/<pid>/fd/*`, and join the two.
+    fn enumerate() -> HashMap<SocketKey, SocketOwner> {
+        let mut by_inode: HashMap<String, SocketKey> = HashMap::new();
+        for (path, proto) in [
+            ("/proc/net/tcp", "tcp"),
+            ("/proc/net/tcp6", "tcp"),
+            ("/proc/net/udp", "udp"),
+            ("/proc/net/udp6", "udp"),
+        ] {
+            let Ok(content) = std::fs::read_to_string(path) else {
+                continue;
+            };
+            for line in content.lines().skip(1) {
+                let fields: Vec<&str> = line.split_whitespace().collect();
+                if fields.len() < 10 {
+                    continue;
+                }
+                let (Some(local), Some(remote)) = (parse_hex_addr(fields[1]), parse_hex_addr(fields[2])) else {
+                    continue;
+                };
+                by_inode.insert(fields[9].to_string(), (local, remote, proto.to_string()));
+            }
+        }
+
+        let mut owners = HashMap::new();
+        let Ok(proc_entries) = std::fs::read_dir("/proc") else {
+            return owners;
+        };
+        for entry in proc_entries.flatten() {
+            let Some(pid) = entry.file_name().to_str().and_then(|s| s.parse::<u32>().ok()) else {
+                continue;
+            };
+            let Ok(fds) = std::fs::read_dir(entry.path().join("fd")) else {
+                continue;
+            };
+            for fd in fds.flatten() {
+                let Ok(link) = std::fs::read_link(fd.path()) else {
+                    continue;
+                };
+                let Some(inode) = link
+                    .to_str()
+                    .and_then(|s| s.strip_prefix("socket:["))
+                    .and_then(|s| s.strip_suffix(']'))
+                else {
+                    continue;
+                };
+                let Some(key) = by_inode.get(inode) else {
+                    continue;
+                };
+                let comm = std::fs::read_to_string(entry.path().join("comm"))
+                    .map(|s| s.trim().to_string())
+                    .unwrap_or_else(|_| "unknown".to_string());
+                owners.insert(key.clone(), SocketOwner { pid, comm });
+            }
+        }
+        owners
+    }
+}
+
+/// Decode a `/proc/net/tcp`-style hex `ADDR:PORT` field (address stored as
+/// little-endian 32-bit words) into a human `ip:port` string.
+fn parse_hex_addr(field: &str) -> Option<String> {
+    let (addr_hex, port_hex) = field.split_once(':')?;
+    let port = u16::from_str_radix(port_hex, 16).ok()?;
+
+    if addr_hex.len() == 8 {
+        let raw = u32::from_str_radix(addr_hex, 16).ok()?;
+        let [a, b, c, d] = raw.to_le_bytes();
+        Some(format!("{a}.{b}.{c}.{d}:{port}"))
+    } else if addr_hex.len() == 32 {
+        let mut bytes = Vec::with_capacity(16);
+        for chunk in addr_hex.as_bytes().chunks(8) {
+            let word = u32::from_str_radix(std::str::from_utf8(chunk).ok()?, 16).ok()?;
+            bytes.extend_from_slice(&word.to_le_bytes());
+        }
+        let groups: Vec<String> = bytes.chunks(2).map(|c| format!("{:02x}{:02x}", c[0], c[1])).collect();
+        Some(format!("[{}]:{port}", groups.join(":")))
+    } else {
+        None
+    }
+}
@@ -0,0 +1,485 @@
+// SPDX-License-Identifier: Apache-2.0
+//! Daemon supervisor: owns the long-lived `ravn` agent process and exposes a
+//! Unix control socket so `start`/`stop`/`tail`/`dashboard` talk to one
+//! shared daemon instead of each re-spawning (and racily `pkill`-ing) the
+//! agent binary directly.
+
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::process::Command;
+use tokio::sync::broadcast;
+
+use crate::app_cli_dashboard::EventData;
+use crate::app_cli_policy::{self, Policy, PolicyAction, PolicyStatus};
+
+/// How many recent raw event lines the daemon keeps around for `--dry-run`
+/// policy evaluation.
+const DRY_RUN_BUFFER: usize = 2000;
+
+const AGENT_PATH: &str = "artifacts/ravn";
+
+fn runtime_dir() -> PathBuf {
+    let base = std::env::var_os("XDG_RUNTIME_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(std::env::temp_dir);
+    base.join("ravn")
+}
+
+fn state_file_path() -> PathBuf {
+    runtime_dir().join("daemon.json")
+}
+
+fn socket_path() -> PathBuf {
+    runtime_dir().join("control.sock")
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct DaemonState {
+    pid: u32,
+    socket: PathBuf,
+}
+
+/// Requests a connected client can send the daemon, one JSON object per line.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum ControlRequest {
+    /// Stream raw agent stdout lines back to this client.
+    Subscribe,
+    /// Kill the agent (SIGKILL) and exit the daemon, removing its state file
+    /// and control socket.
+    Shutdown,
+    /// Toggle whether the daemon forwards agent output to subscribers.
+    Pause,
+    Resume,
+    /// Replace the active policy and reset its match counters.
+    ApplyPolicy { policy: Policy },
+    /// Evaluate `policy` against the daemon's recent-event buffer without
+    /// making it active.
+    DryRunPolicy { policy: Policy },
+    /// Report the active policy and its live per-rule match counts.
+    PolicyStatus,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ControlResponse {
+    Ok,
+    Event { line: String },
+    Error { message: String },
+    PolicyStatus { status: PolicyStatus },
+}
+
+fn is_daemon_running(state: &DaemonState) -> bool {
+    // Signal 0: doesn't actually send a signal, just checks the PID exists and
+    // is ours to signal.
+    nix::sys::signal::kill(nix::unistd::Pid::from_raw(state.pid as i32), None).is_ok()
+}
+
+fn read_state() -> Option<DaemonState> {
+    let content = std::fs::read_to_string(state_file_path()).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
+/// Start the daemon if one isn't already running, by re-exec'ing this binary
+/// in its hidden `supervisor-daemon` mode, detached from the current session.
+pub async fn start_agent() -> Result<()> {
+    if let Some(state) = read_state() {
+        if is_daemon_running(&state) {
+            println!("daemon already running (PID {})", state.pid);
+            return Ok(());
+        }
+    }
+
+    std::fs::create_dir_all(runtime_dir()).context("create runtime dir")?;
+
+    let agent = PathBuf::from(AGENT_PATH);
+    if !agent.exists() {
+        bail!("agent binary not found at {:?}", agent);
+    }
+
+    let exe = std::env::current_exe().context("resolve current exe")?;
+    let mut child = std::process::Command::new(exe);
+    child
+        .arg("supervisor-daemon")
+        .arg(&agent)
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null());
+    // Detach into its own session so it survives the launching shell exiting,
+    // instead of relying on `sudo`+backgrounding at the call site.
+    unsafe {
+        use std::os::unix::process::CommandExt;
+        child.pre_exec(|| {
+            nix::unistd::setsid().map_err(|e| std::io::Error::from_raw_os_error(e as i32))?;
+            Ok(())
+        });
+    }
+    child.spawn().context("spawn supervisor daemon")?;
+
+    wait_for_daemon_ready().await
+}
+
+/// Poll for the daemon to publish its state file and accept a connection on
+/// its control socket, so callers that immediately `subscribe()` after
+/// `start_agent()` don't race the child's startup (exec + Tokio runtime init
+/// + agent spawn takes far longer than the few instructions it takes us to
+/// get here).
+async fn wait_for_daemon_ready() -> Result<()> {
+    for _ in 0..50 {
+        if let Some(state) = read_state() {
+            if UnixStream::connect(&state.socket).await.is_ok() {
+                return Ok(());
+            }
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    bail!("daemon did not become ready in time")
+}
+
+/// Connect to the running daemon and ask it to shut down gracefully, falling
+/// back to SIGTERM/SIGKILL against its recorded PID if it is still running
+/// two seconds later (e.g. because the socket is gone).
+pub async fn stop_agent() -> Result<()> {
+    let Some(state) = read_state() else {
+        println!("no daemon state found; nothing to stop");
+        return Ok(());
+    };
+
+    if let Ok(mut stream) = UnixStream::connect(&state.socket).await {
+        send_request(&mut stream, &ControlRequest::Shutdown).await?;
+    }
+
+    for _ in 0..20 {
+        if !is_daemon_running(&state) {
+            return Ok(());
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+
+    let pid = nix::unistd::Pid::from_raw(state.pid as i32);
+    let _ = nix::sys::signal::kill(pid, nix::sys::signal::Signal::SIGTERM);
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    if is_daemon_running(&state) {
+        let _ = nix::sys::signal::kill(pid, nix::sys::signal::Signal::SIGKILL);
+    }
+
+    Ok(())
+}
+
+async fn send_request(stream: &mut UnixStream, req: &ControlRequest) -> Result<()> {
+    let mut line = serde_json::to_string(req)?;
+    line.push('\n');
+    stream.write_all(line.as_bytes()).await?;
+    Ok(())
+}
+
+/// Send a one-off control request (e.g. `Pause`/`Resume`) to the running
+/// daemon and wait for its acknowledgement.
+pub async fn send_control(req: ControlRequest) -> Result<()> {
+    let Some(state) = read_state() else {
+        bail!("no daemon running; start one with `ravn-ctl start`");
+    };
+    let mut stream = UnixStream::connect(&state.socket)
+        .await
+        .context("connect to daemon control socket")?;
+    send_request(&mut stream, &req).await?;
+
+    let mut lines = tokio::io::BufReader::new(stream).lines();
+    match lines.next_line().await? {
+        Some(line) => match serde_json::from_str::<ControlResponse>(&line)? {
+            ControlResponse::Ok => Ok(()),
+            ControlResponse::Error { message } => bail!("daemon error: {message}"),
+            ControlResponse::Event { .. } | ControlResponse::PolicyStatus { .. } => Ok(()),
+        },
+        None => bail!("daemon closed the connection without replying"),
+    }
+}
+
+/// Push `policy` to the running daemon so it takes effect immediately.
+pub async fn apply_policy(policy: Policy) -> Result<()> {
+    send_control(ControlRequest::ApplyPolicy { policy }).await
+}
+
+/// Fetch the daemon's active policy and live per-rule match counts.
+pub async fn policy_status() -> Result<PolicyStatus> {
+    fetch_policy_status(ControlRequest::PolicyStatus).await
+}
+
+/// Evaluate `policy` against the daemon's recent-event buffer without
+/// committing it, so operators can preview match counts before applying.
+pub async fn dry_run_policy(policy: Policy) -> Result<PolicyStatus> {
+    fetch_policy_status(ControlRequest::DryRunPolicy { policy }).await
+}
+
+async fn fetch_policy_status(req: ControlRequest) -> Result<PolicyStatus> {
+    let Some(state) = read_state() else {
+        bail!("no daemon running; start one with `ravn-ctl start`");
+    };
+    let mut stream = UnixStream::connect(&state.socket)
+        .await
+        .context("connect to daemon control socket")?;
+    send_request(&mut stream, &req).await?;
+
+    let mut lines = tokio::io::BufReader::new(stream).lines();
+    match lines.next_line().await? {
+        Some(line) => match serde_json::from_str::<ControlResponse>(&line)? {
+            ControlResponse::PolicyStatus { status } => Ok(status),
+            ControlResponse::Error { message } => bail!("daemon error: {message}"),
+            _ => bail!("unexpected daemon response to policy request"),
+        },
+        None => bail!("daemon closed the connection without replying"),
+    }
+}
+
+/// Connect to the running daemon, subscribe, and forward event lines into a
+/// channel — used by both `Tail` and `Dashboard` so multiple clients can
+/// attach to one agent instead of each re-spawning it.
+pub async fn subscribe() -> Result<tokio::sync::mpsc::Receiver<String>> {
+    let Some(state) = read_state() else {
+        bail!("no daemon running; start one with `ravn-ctl start`");
+    };
+    if !is_daemon_running(&state) {
+        bail!("daemon state is stale (PID {} is gone); restart with `ravn-ctl start`", state.pid);
+    }
+
+    let mut stream = UnixStream::connect(&state.socket)
+        .await
+        .context("connect to daemon control socket")?;
+    send_request(&mut stream, &ControlRequest::Subscribe).await?;
+
+    let (tx, rx) = tokio::sync::mpsc::channel::<String>(1024);
+    tokio::spawn(async move {
+        let mut lines = tokio::io::BufReader::new(stream).lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            let Ok(ControlResponse::Event { line }) = serde_json::from_str(&line) else {
+                continue;
+            };
+            if tx.send(line).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    Ok(rx)
+}
+
+/// The daemon's own main loop: owns the agent child, fans its stdout out to
+/// any number of subscribed clients, and answers control requests.
+pub async fn run_supervisor(agent: PathBuf) -> Result<()> {
+    std::fs::create_dir_all(runtime_dir()).context("create runtime dir")?;
+    let socket = socket_path();
+    let _ = std::fs::remove_file(&socket);
+
+    let mut child = Command::new(&agent)
+        .stdout(std::process::Stdio::piped())
+        .stderr(std::process::Stdio::piped())
+        .spawn()
+        .context("spawn agent")?;
+
+    std::fs::write(
+        state_file_path(),
+        serde_json::to_string(&DaemonState { pid: std::process::id(), socket: socket.clone() })?,
+    )
+    .context("write daemon state file")?;
+
+    let (events_tx, _) = broadcast::channel::<String>(4096);
+    let paused = Arc::new(AtomicBool::new(false));
+    let policy = Arc::new(Mutex::new(Policy::default()));
+    let policy_matches = Arc::new(Mutex::new(Vec::<usize>::new()));
+    let recent_lines = Arc::new(Mutex::new(VecDeque::<String>::with_capacity(DRY_RUN_BUFFER)));
+
+    let stdout = child.stdout.take().context("take agent stdout")?;
+    let reader_tx = events_tx.clone();
+    let reader_paused = paused.clone();
+    let reader_policy = policy.clone();
+    let reader_policy_matches = policy_matches.clone();
+    let reader_recent_lines = recent_lines.clone();
+    tokio::spawn(async move {
+        let mut lines = tokio::io::BufReader::new(stdout).lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            if reader_paused.load(Ordering::Relaxed) {
+                continue;
+            }
+
+            {
+                let mut buf = reader_recent_lines.lock().unwrap();
+                buf.push_back(line.clone());
+                if buf.len() > DRY_RUN_BUFFER {
+                    buf.pop_front();
+                }
+            }
+
+            let forward = apply_policy_to_line(&line, &reader_policy, &reader_policy_matches);
+            if forward {
+                let _ = reader_tx.send(line);
+            }
+        }
+    });
+
+    let listener = UnixListener::bind(&socket).context("bind control socket")?;
+    loop {
+        let (stream, _) = listener.accept().await.context("accept control connection")?;
+        let client_rx = events_tx.subscribe();
+        let paused = paused.clone();
+        let policy = policy.clone();
+        let policy_matches = policy_matches.clone();
+        let recent_lines = recent_lines.clone();
+
+        match handle_client(stream, client_rx, &paused, &policy, &policy_matches, &recent_lines).await {
+            Ok(ShutdownRequested(true)) => break,
+            Ok(ShutdownRequested(false)) => {}
+            Err(e) => eprintln!("control connection error: {e:#}"),
+        }
+    }
+
+    let _ = child.start_kill();
+    let _ = std::fs::remove_file(state_file_path());
+    let _ = std::fs::remove_file(&socket);
+    Ok(())
+}
+
+struct ShutdownRequested(bool);
+
+/// Evaluate the active policy against one raw agent line, bumping the
+/// matched rule's counter and acting on it. Returns whether the line should
+/// still be forwarded to subscribers (false only for `Drop`).
+fn apply_policy_to_line(
+    line: &str,
+    policy: &Arc<Mutex<Policy>>,
+    policy_matches: &Arc<Mutex<Vec<usize>>>,
+) -> bool {
+    let Some(event) = EventData::from_json_line(line) else {
+        return true;
+    };
+
+    let policy = policy.lock().unwrap();
+    let Some(idx) = policy.matching_rule(&event) else {
+        return true;
+    };
+    let action = policy.rules[idx].action;
+    drop(policy);
+
+    if let Some(count) = policy_matches.lock().unwrap().get_mut(idx) {
+        *count += 1;
+    }
+
+    match action {
+        PolicyAction::Drop => false,
+        PolicyAction::Alert => {
+            eprintln!("[policy alert] comm={} file={} etype={}", event.comm, event.file, event.event_type);
+            true
+        }
+        PolicyAction::Log => true,
+    }
+}
+
+async fn handle_client(
+    stream: UnixStream,
+    mut events_rx: broadcast::Receiver<String>,
+    paused: &Arc<AtomicBool>,
+    policy: &Arc<Mutex<Policy>>,
+    policy_matches: &Arc<Mutex<Vec<usize>>>,
+    recent_lines: &Arc<Mutex<VecDeque<String>>>,
+) -> Result<ShutdownRequested> {
+    let (reader, mut writer) = stream.into_split();
+    let mut lines = tokio::io::BufReader::new(reader).lines();
+
+    while let Some(line) = lines.next_line().await? {
+        let Ok(req) = serde_json::from_str::<ControlRequest>(&line) else {
+            continue;
+        };
+
+        match req {
+            ControlRequest::Shutdown => return Ok(ShutdownRequested(true)),
+            ControlRequest::Pause => {
+                paused.store(true, Ordering::Relaxed);
+                reply_ok(&mut writer).await?;
+            }
+            ControlRequest::Resume => {
+                paused.store(false, Ordering::Relaxed);
+                reply_ok(&mut writer).await?;
+            }
+            ControlRequest::ApplyPolicy { policy: new_policy } => {
+                if let Err(e) = new_policy.validate() {
+                    reply_error(&mut writer, &e.to_string()).await?;
+                } else {
+                    let rule_count = new_policy.rules.len();
+                    *policy.lock().unwrap() = new_policy;
+                    *policy_matches.lock().unwrap() = vec![0; rule_count];
+                    reply_ok(&mut writer).await?;
+                }
+            }
+            ControlRequest::DryRunPolicy { policy: candidate } => {
+                if let Err(e) = candidate.validate() {
+                    reply_error(&mut writer, &e.to_string()).await?;
+                } else {
+                    let lines: Vec<String> = recent_lines.lock().unwrap().iter().cloned().collect();
+                    let events: Vec<EventData> =
+                        lines.iter().filter_map(|l| EventData::from_json_line(l)).collect();
+                    let counts = candidate.evaluate(&events);
+                    let status = app_cli_policy::status_from(&candidate, counts);
+                    reply_policy_status(&mut writer, status).await?;
+                }
+            }
+            ControlRequest::PolicyStatus => {
+                let active = policy.lock().unwrap().clone();
+                let counts = policy_matches.lock().unwrap().clone();
+                let status = app_cli_policy::status_from(&active, counts);
+                reply_policy_status(&mut writer, status).await?;
+            }
+            ControlRequest::Subscribe => {
+                loop {
+                    let line = match events_rx.recv().await {
+                        Ok(line) => line,
+                        // The client fell behind the broadcast buffer, not disconnected —
+                        // tokio's docs say `Lagged` doesn't close the receiver. Skip the
+                        // missed lines and keep streaming instead of dropping the client.
+                        Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                        Err(broadcast::error::RecvError::Closed) => break,
+                    };
+                    let resp = ControlResponse::Event { line };
+                    let mut json = serde_json::to_string(&resp)?;
+                    json.push('\n');
+                    if writer.write_all(json.as_bytes()).await.is_err() {
+                        break;
+                    }
+                }
+                return Ok(ShutdownRequested(false));
+            }
+        }
+    }
+
+    Ok(ShutdownRequested(false))
+}
+
+async fn reply_ok(writer: &mut (impl AsyncWriteExt + Unpin)) -> Result<()> {
+    let mut json = serde_json::to_string(&ControlResponse::Ok)?;
+    json.push('\n');
+    writer.write_all(json.as_bytes()).await?;
+    Ok(())
+}
+
+async fn reply_error(writer: &mut (impl AsyncWriteExt + Unpin), message: &str) -> Result<()> {
+    let mut json = serde_json::to_string(&ControlResponse::Error { message: message.to_string() })?;
+    json.push('\n');
+    writer.write_all(json.as_bytes()).await?;
+    Ok(())
+}
+
+async fn reply_policy_status(writer: &mut (impl AsyncWriteExt + Unpin), status: PolicyStatus) -> Result<()> {
+    let mut json = serde_json::to_string(&ControlResponse::PolicyStatus { status })?;
+    json.push('\n');
+    writer.write_all(json.as_bytes()).await?;
+    Ok(())
+}
+
+pub fn is_running() -> bool {
+    read_state().map(|s| is_daemon_running(&s)).unwrap_or(false)
+}